@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicU64;
@@ -77,6 +78,8 @@ use crate::protocol::AgentReasoningSectionBreakEvent;
 use crate::protocol::ApplyPatchApprovalRequestEvent;
 use crate::protocol::AskForApproval;
 use crate::protocol::BackgroundEventEvent;
+use crate::protocol::CompactedItem;
+use crate::protocol::CompactionStatsResponseEvent;
 use crate::protocol::ErrorEvent;
 use crate::protocol::Event;
 use crate::protocol::EventMsg;
@@ -84,6 +87,7 @@ use crate::protocol::ExecApprovalRequestEvent;
 use crate::protocol::ListCustomPromptsResponseEvent;
 use crate::protocol::Op;
 use crate::protocol::RateLimitSnapshot;
+use crate::protocol::ReasoningEffortResponseEvent;
 use crate::protocol::ReviewDecision;
 use crate::protocol::ReviewOutputEvent;
 use crate::protocol::SandboxPolicy;
@@ -92,18 +96,22 @@ use crate::protocol::StreamErrorEvent;
 use crate::protocol::Submission;
 use crate::protocol::TokenCountEvent;
 use crate::protocol::TokenUsage;
+use crate::protocol::TokenUsageInfo;
 use crate::protocol::TurnDiffEvent;
 use crate::protocol::WebSearchBeginEvent;
 use crate::rollout::RolloutRecorder;
 use crate::rollout::RolloutRecorderParams;
 use crate::shell;
 use crate::state::ActiveTurn;
+use crate::state::CompactionStats;
 use crate::state::SessionServices;
 use crate::state::SessionState;
 use crate::state::TaskKind;
 use crate::tasks::CompactTask;
+use crate::tasks::RangeCompactTask;
 use crate::tasks::RegularTask;
 use crate::tasks::ReviewTask;
+use crate::tasks::ToolOutputCompactTask;
 use crate::tools::ToolRouter;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::parallel::ToolCallRuntime;
@@ -266,6 +274,10 @@ pub(crate) struct TurnContext {
     pub(crate) sandbox_policy: SandboxPolicy,
     pub(crate) shell_environment_policy: ShellEnvironmentPolicy,
     pub(crate) tools_config: ToolsConfig,
+    /// Tool calls with larger arguments are rejected before dispatch.
+    pub(crate) max_tool_arg_bytes: usize,
+    /// Retry budget for compaction; `None` uses the provider's stream retries.
+    pub(crate) compaction_max_retries: Option<u64>,
     pub(crate) is_review_mode: bool,
     pub(crate) final_output_json_schema: Option<Value>,
     pub(crate) codex_linux_sandbox_exe: Option<PathBuf>,
@@ -337,6 +349,36 @@ impl SessionConfiguration {
         }
         next_configuration
     }
+
+    /// Snapshot of the settings the next turn will run with.
+    pub(crate) fn turn_context_item(&self) -> TurnContextItem {
+        TurnContextItem {
+            cwd: self.cwd.clone(),
+            approval_policy: self.approval_policy,
+            sandbox_policy: self.sandbox_policy.clone(),
+            model: self.model.clone(),
+            effort: self.model_reasoning_effort,
+            summary: self.model_reasoning_summary,
+        }
+    }
+
+    /// Carry over the model and reasoning effort recorded by the last
+    /// `TurnContext` item in a resumed rollout, so overrides made during the
+    /// original session survive a resume.
+    pub(crate) fn with_resumed_turn_context(&self, rollout_items: &[RolloutItem]) -> Self {
+        let Some(item) = rollout_items.iter().rev().find_map(|item| match item {
+            RolloutItem::TurnContext(item) => Some(item),
+            _ => None,
+        }) else {
+            return self.clone();
+        };
+        let model = find_family_for_model(&item.model).map(|_| item.model.clone());
+        self.apply(&SessionSettingsUpdate {
+            model,
+            reasoning_effort: Some(item.effort),
+            ..Default::default()
+        })
+    }
 }
 
 #[derive(Default, Clone)]
@@ -401,6 +443,8 @@ impl Session {
             sandbox_policy: session_configuration.sandbox_policy.clone(),
             shell_environment_policy: config.shell_environment_policy.clone(),
             tools_config,
+            max_tool_arg_bytes: config.max_tool_arg_bytes,
+            compaction_max_retries: config.compaction_max_retries,
             is_review_mode: false,
             final_output_json_schema: None,
             codex_linux_sandbox_exe: config.codex_linux_sandbox_exe.clone(),
@@ -443,6 +487,12 @@ impl Session {
                 RolloutRecorderParams::resume(resumed_history.rollout_path.clone()),
             ),
         };
+        let session_configuration = match &initial_history {
+            InitialHistory::Resumed(resumed_history) => {
+                session_configuration.with_resumed_turn_context(&resumed_history.history)
+            }
+            InitialHistory::New | InitialHistory::Forked(_) => session_configuration,
+        };
 
         // Error messages to dispatch after SessionConfigured is sent.
         let mut post_session_configured_error_events = Vec::<Event>::new();
@@ -633,6 +683,40 @@ impl Session {
         state.session_configuration = state.session_configuration.apply(&updates);
     }
 
+    /// Apply `updates` to subsequent turns, persist the resulting turn context,
+    /// and report it via `EventMsg::TurnContextChanged`.
+    async fn change_turn_context(&self, sub_id: String, updates: SessionSettingsUpdate) {
+        let turn_context_item = {
+            let mut state = self.state.lock().await;
+            state.session_configuration = state.session_configuration.apply(&updates);
+            state.session_configuration.turn_context_item()
+        };
+        self.persist_rollout_items(&[RolloutItem::TurnContext(turn_context_item.clone())])
+            .await;
+        let event = Event {
+            id: sub_id,
+            msg: EventMsg::TurnContextChanged(turn_context_item),
+        };
+        self.send_event_raw(event).await;
+    }
+
+    /// Switch the model used by all future turns. The client is rebuilt from
+    /// the session configuration each turn, so auth, effort, and summary
+    /// settings carry over. Models without a known family are rejected.
+    pub(crate) async fn set_model(&self, sub_id: String, model: String) -> CodexResult<()> {
+        if find_family_for_model(&model).is_none() {
+            return Err(CodexErr::UnsupportedOperation(format!(
+                "unknown model: {model}"
+            )));
+        }
+        let updates = SessionSettingsUpdate {
+            model: Some(model),
+            ..Default::default()
+        };
+        self.change_turn_context(sub_id, updates).await;
+        Ok(())
+    }
+
     pub(crate) async fn new_turn(&self, updates: SessionSettingsUpdate) -> Arc<TurnContext> {
         let sub_id = self.next_internal_sub_id();
         self.new_turn_with_sub_id(sub_id, updates).await
@@ -860,6 +944,20 @@ impl Session {
         self.persist_rollout_response_items(items).await;
     }
 
+    /// Append an assistant message to history and the rollout without a model
+    /// round-trip. Only unit tests hold a `Session` directly, so this stays
+    /// test-only; it lets them seed replies instead of hand-building items.
+    #[cfg(test)]
+    pub(crate) async fn record_assistant_message(&self, text: impl Into<String>) {
+        let item = ResponseItem::Message {
+            id: None,
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText { text: text.into() }],
+        };
+        self.record_conversation_items(std::slice::from_ref(&item))
+            .await;
+    }
+
     fn reconstruct_history_from_rollout(
         &self,
         turn_context: &TurnContext,
@@ -871,6 +969,12 @@ impl Session {
                 RolloutItem::ResponseItem(response_item) => {
                     history.record_items(std::iter::once(response_item));
                 }
+                RolloutItem::Compacted(CompactedItem {
+                    replacement_history: Some(replacement_history),
+                    ..
+                }) => {
+                    history.replace(replacement_history.clone());
+                }
                 RolloutItem::Compacted(compacted) => {
                     let snapshot = history.get_history();
                     let user_messages = collect_user_messages(&snapshot);
@@ -878,12 +982,15 @@ impl Session {
                         self.build_initial_context(turn_context),
                         &user_messages,
                         &compacted.message,
+                        compacted.compacted_at.as_deref(),
+                        compacted.model.as_deref(),
                     );
                     history.replace(rebuilt);
                 }
                 _ => {}
             }
         }
+        history.repair_interrupted_tool_calls();
         history.get_history()
     }
 
@@ -893,9 +1000,49 @@ impl Session {
         state.record_items(items.iter());
     }
 
-    async fn replace_history(&self, items: Vec<ResponseItem>) {
+    /// Swap in a compacted history and update the session's compaction totals.
+    async fn replace_compacted_history(&self, items: Vec<ResponseItem>) {
         let mut state = self.state.lock().await;
-        state.replace_history(items);
+        state.replace_history_after_compaction(items);
+    }
+
+    /// Swap `summaries` (keyed by `call_id`) in for the matching tool outputs
+    /// and return the resulting history.
+    pub(crate) async fn summarize_tool_outputs_in_history(
+        &self,
+        summaries: &HashMap<String, String>,
+    ) -> Vec<ResponseItem> {
+        let mut state = self.state.lock().await;
+        let history = compact::replace_tool_outputs(state.history_snapshot(), summaries);
+        state.replace_history(history.clone());
+        history
+    }
+
+    /// Splice `summary` in place of `range` and return the resulting history,
+    /// provided the items in `range` still match `expected`. Fails when the
+    /// history changed underneath, e.g. because a compaction shrank it.
+    pub(crate) async fn splice_range_summary_in_history(
+        &self,
+        range: Range<usize>,
+        expected: &[ResponseItem],
+        summary: &str,
+    ) -> CodexResult<Vec<ResponseItem>> {
+        let mut state = self.state.lock().await;
+        let history = state.history_snapshot();
+        if history.get(range.clone()) != Some(expected) {
+            return Err(CodexErr::UnsupportedOperation(format!(
+                "history items {}..{} changed while they were being summarized",
+                range.start, range.end
+            )));
+        }
+        let history = compact::splice_range_summary(history, range, summary);
+        state.replace_history(history.clone());
+        Ok(history)
+    }
+
+    pub(crate) async fn compaction_stats(&self) -> CompactionStats {
+        let state = self.state.lock().await;
+        state.compaction_stats
     }
 
     async fn persist_rollout_response_items(&self, items: &[ResponseItem]) {
@@ -939,6 +1086,40 @@ impl Session {
         state.history_snapshot()
     }
 
+    /// Summarize history items `[start, end)` into a single message and splice
+    /// it back in place, leaving the rest of the history untouched.
+    pub(crate) async fn compact_range(
+        &self,
+        start: usize,
+        end: usize,
+        turn_context: &TurnContext,
+    ) -> CodexResult<()> {
+        compact::compact_range(self, turn_context, start..end).await
+    }
+
+    /// History recorded after the most recent compaction, or the full history
+    /// when the conversation has not been compacted.
+    pub(crate) async fn history_since_last_compaction(&self) -> Vec<ResponseItem> {
+        let history = self.history_snapshot().await;
+        compact::items_since_last_compaction(&history)
+    }
+
+    /// Text of the most recent assistant message anywhere in the history.
+    pub(crate) async fn last_assistant_message(&self) -> Option<String> {
+        let history = self.history_snapshot().await;
+        history.iter().rev().find_map(|item| match item {
+            ResponseItem::Message { role, content, .. } if role == "assistant" => {
+                compact::content_items_to_text(content)
+            }
+            _ => None,
+        })
+    }
+
+    pub(crate) async fn token_usage_info(&self) -> Option<TokenUsageInfo> {
+        let state = self.state.lock().await;
+        state.token_info_and_rate_limits().0
+    }
+
     pub(crate) async fn clone_history(&self) -> ConversationHistory {
         let state = self.state.lock().await;
         state.clone_history()
@@ -1157,6 +1338,36 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                 sess.update_settings(updates).await;
             }
 
+            Op::SetReasoningEffort { effort } => {
+                let updates = SessionSettingsUpdate {
+                    reasoning_effort: Some(effort),
+                    ..Default::default()
+                };
+                sess.change_turn_context(sub.id.clone(), updates).await;
+            }
+            Op::SetModel { model } => {
+                if let Err(e) = sess.set_model(sub.id.clone(), model).await {
+                    let event = Event {
+                        id: sub.id.clone(),
+                        msg: EventMsg::Error(ErrorEvent {
+                            message: e.to_string(),
+                        }),
+                    };
+                    sess.send_event_raw(event).await;
+                }
+            }
+            Op::GetReasoningEffort => {
+                let effort = {
+                    let state = sess.state.lock().await;
+                    state.session_configuration.model_reasoning_effort
+                };
+                let event = Event {
+                    id: sub.id.clone(),
+                    msg: EventMsg::ReasoningEffortResponse(ReasoningEffortResponseEvent { effort }),
+                };
+                sess.send_event_raw(event).await;
+            }
+
             Op::UserInput { .. } | Op::UserTurn { .. } => {
                 let (items, updates) = match sub.op {
                     Op::UserTurn {
@@ -1308,6 +1519,34 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                 };
                 sess.send_event_raw(event).await;
             }
+            Op::GetCompactionStats => {
+                let CompactionStats {
+                    count,
+                    total_items_dropped,
+                    total_tokens_saved,
+                } = sess.compaction_stats().await;
+                let event = Event {
+                    id: sub.id.clone(),
+                    msg: EventMsg::CompactionStatsResponse(CompactionStatsResponseEvent {
+                        count,
+                        total_items_dropped,
+                        total_tokens_saved,
+                    }),
+                };
+                sess.send_event_raw(event).await;
+            }
+            Op::GetCompactionConfig => {
+                let turn_context = sess
+                    .new_turn_with_sub_id(sub.id.clone(), SessionSettingsUpdate::default())
+                    .await;
+                let event = Event {
+                    id: sub.id.clone(),
+                    msg: EventMsg::CompactionConfigResponse(compact::compaction_config(
+                        turn_context.as_ref(),
+                    )),
+                };
+                sess.send_event_raw(event).await;
+            }
             Op::Compact => {
                 let turn_context = sess
                     .new_turn_with_sub_id(sub.id.clone(), SessionSettingsUpdate::default())
@@ -1323,6 +1562,20 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                         .await;
                 }
             }
+            Op::CompactToolOutputs => {
+                let turn_context = sess
+                    .new_turn_with_sub_id(sub.id.clone(), SessionSettingsUpdate::default())
+                    .await;
+                sess.spawn_task(turn_context, Vec::new(), ToolOutputCompactTask)
+                    .await;
+            }
+            Op::CompactRange { start, end } => {
+                let turn_context = sess
+                    .new_turn_with_sub_id(sub.id.clone(), SessionSettingsUpdate::default())
+                    .await;
+                sess.spawn_task(turn_context, Vec::new(), RangeCompactTask { start, end })
+                    .await;
+            }
             Op::Shutdown => {
                 sess.abort_all_tasks(TurnAbortReason::Interrupted).await;
                 info!("Shutting down Codex instance");
@@ -1380,6 +1633,24 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
                 };
                 sess.send_event_raw(event).await;
             }
+            Op::ExportTranscript { path, format } => {
+                let path = {
+                    let state = sess.state.lock().await;
+                    state.session_configuration.cwd.join(path)
+                };
+                let history = sess.history_snapshot().await;
+                let msg = match crate::transcript_export::write_transcript(&history, format, &path)
+                    .await
+                {
+                    Ok(()) => EventMsg::BackgroundEvent(BackgroundEventEvent {
+                        message: format!("Exported transcript to {}", path.display()),
+                    }),
+                    Err(e) => EventMsg::Error(ErrorEvent {
+                        message: format!("Failed to export transcript to {}: {e}", path.display()),
+                    }),
+                };
+                sess.send_event_raw(Event { id: sub.id, msg }).await;
+            }
             Op::Review { review_request } => {
                 let turn_context = sess
                     .new_turn_with_sub_id(sub.id.clone(), SessionSettingsUpdate::default())
@@ -1467,6 +1738,8 @@ async fn spawn_review_thread(
         sandbox_policy: parent_turn_context.sandbox_policy.clone(),
         shell_environment_policy: parent_turn_context.shell_environment_policy.clone(),
         cwd: parent_turn_context.cwd.clone(),
+        max_tool_arg_bytes: parent_turn_context.max_tool_arg_bytes,
+        compaction_max_retries: parent_turn_context.compaction_max_retries,
         is_review_mode: true,
         final_output_json_schema: None,
         codex_linux_sandbox_exe: parent_turn_context.codex_linux_sandbox_exe.clone(),
@@ -2301,11 +2574,12 @@ mod tests {
     use crate::config::ConfigToml;
     use crate::config_types::McpServerConfig;
     use crate::config_types::McpServerTransportConfig;
+    use crate::conversation_history::INTERRUPTED_CALL_OUTPUT;
     use crate::exec::ExecToolCallOutput;
     use crate::mcp::auth::McpAuthStatusEntry;
+    use crate::token_estimate::estimate_tokens;
     use crate::tools::format_exec_output_str;
 
-    use crate::protocol::CompactedItem;
     use crate::protocol::InitialHistory;
     use crate::protocol::ResumedHistory;
     use crate::state::TaskKind;
@@ -2322,6 +2596,7 @@ mod tests {
     use codex_protocol::models::ContentItem;
     use codex_protocol::models::ResponseItem;
     use codex_protocol::protocol::McpAuthStatus;
+    use std::collections::BTreeMap;
     use std::time::Duration;
     use tokio::time::sleep;
 
@@ -2344,6 +2619,199 @@ mod tests {
         assert_eq!(expected, reconstructed);
     }
 
+    #[tokio::test]
+    async fn last_assistant_message_scans_whole_history() {
+        let (session, _turn_context) = make_session_and_context();
+        let user = |text: &str| ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        };
+
+        session.record_into_history(&[user("hello")]).await;
+        assert_eq!(None, session.last_assistant_message().await);
+
+        session.record_assistant_message("first reply").await;
+        session.record_into_history(&[user("and again")]).await;
+        session.record_assistant_message("second reply").await;
+        session.record_into_history(&[user("one more")]).await;
+
+        assert_eq!(
+            Some("second reply".to_string()),
+            session.last_assistant_message().await
+        );
+    }
+
+    #[tokio::test]
+    async fn compaction_stats_accumulate_across_compactions() {
+        let (session, _turn_context) = make_session_and_context();
+        let user = |text: String| ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText { text }],
+        };
+
+        session.record_into_history(&[user("a".repeat(400))]).await;
+        session.record_assistant_message("b".repeat(400)).await;
+        session.record_into_history(&[user("c".repeat(400))]).await;
+        let first_before = session.history_snapshot().await;
+        let first_after = vec![user("summary one".to_string())];
+        session.replace_compacted_history(first_after.clone()).await;
+
+        session.record_assistant_message("d".repeat(800)).await;
+        let second_before = session.history_snapshot().await;
+        let second_after = vec![user("summary two".to_string())];
+        session
+            .replace_compacted_history(second_after.clone())
+            .await;
+
+        let saved = |before: &[ResponseItem], after: &[ResponseItem]| {
+            (estimate_tokens(before) - estimate_tokens(after)) as u64
+        };
+        assert_eq!(
+            CompactionStats {
+                count: 2,
+                total_items_dropped: 3,
+                total_tokens_saved: saved(&first_before, &first_after)
+                    + saved(&second_before, &second_after),
+            },
+            session.compaction_stats().await
+        );
+    }
+
+    #[tokio::test]
+    async fn tool_output_compaction_is_not_counted_in_compaction_stats() {
+        let (session, _turn_context) = make_session_and_context();
+        let call = ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: r#"{"command":["cargo","test"]}"#.to_string(),
+            call_id: "call-1".to_string(),
+        };
+        let output = |content: String| ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload {
+                content,
+                success: Some(true),
+            },
+        };
+        session
+            .record_into_history(&[call.clone(), output("x".repeat(40_000))])
+            .await;
+
+        let summaries = HashMap::from([("call-1".to_string(), "all tests pass".to_string())]);
+        session.summarize_tool_outputs_in_history(&summaries).await;
+
+        assert_eq!(
+            vec![call, output("all tests pass".to_string())],
+            session.history_snapshot().await
+        );
+        assert_eq!(CompactionStats::default(), session.compaction_stats().await);
+    }
+
+    #[tokio::test]
+    async fn range_splice_fails_when_history_changed() {
+        let (session, _turn_context) = make_session_and_context();
+        let user = |text: &str| ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        };
+        session.record_into_history(&[user("tangent")]).await;
+        session.record_assistant_message("resolved").await;
+        let summarized = session.history_snapshot().await;
+        // A concurrent compaction shrank the history while the range was
+        // being summarized.
+        let compacted = vec![user("summary")];
+        session.replace_compacted_history(compacted.clone()).await;
+
+        let err = session
+            .splice_range_summary_in_history(0..2, &summarized, "tangent summary")
+            .await
+            .expect_err("stale range should be rejected");
+
+        assert_eq!(
+            "unsupported operation: history items 0..2 changed while they were being summarized",
+            err.to_string()
+        );
+        assert_eq!(compacted, session.history_snapshot().await);
+    }
+
+    #[test]
+    fn reconstruct_history_restores_replacement_history() {
+        let (session, turn_context) = make_session_and_context();
+        let message = |role: &str, text: &str| ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        };
+        let replacement_history = vec![
+            message("user", "run the tests"),
+            message("assistant", "done"),
+        ];
+        let rollout_items = vec![
+            RolloutItem::ResponseItem(message("user", "run the tests")),
+            RolloutItem::ResponseItem(message("assistant", "a very long reply")),
+            RolloutItem::Compacted(CompactedItem {
+                message: "Summarized 1 large tool output(s).".to_string(),
+                compacted_at: None,
+                model: None,
+                replacement_history: Some(replacement_history.clone()),
+            }),
+            RolloutItem::ResponseItem(message("user", "thanks")),
+        ];
+
+        assert_eq!(
+            [replacement_history, vec![message("user", "thanks")]].concat(),
+            session.reconstruct_history_from_rollout(&turn_context, &rollout_items)
+        );
+    }
+
+    #[test]
+    fn reconstruct_history_repairs_unpaired_tail_call() {
+        let (session, turn_context) = make_session_and_context();
+        let user = ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: "run the tests".to_string(),
+            }],
+        };
+        let call = ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: r#"{"command":["cargo","test"]}"#.to_string(),
+            call_id: "call-1".to_string(),
+        };
+        let rollout_items = vec![
+            RolloutItem::ResponseItem(user.clone()),
+            RolloutItem::ResponseItem(call.clone()),
+        ];
+
+        let reconstructed = session.reconstruct_history_from_rollout(&turn_context, &rollout_items);
+
+        assert_eq!(
+            vec![
+                user,
+                call,
+                ResponseItem::FunctionCallOutput {
+                    call_id: "call-1".to_string(),
+                    output: FunctionCallOutputPayload {
+                        content: INTERRUPTED_CALL_OUTPUT.to_string(),
+                        success: None,
+                    },
+                },
+            ],
+            reconstructed
+        );
+    }
+
     #[test]
     fn record_initial_history_reconstructs_resumed_transcript() {
         let (session, turn_context) = make_session_and_context();
@@ -2412,7 +2880,7 @@ mod tests {
             timed_out: false,
         };
 
-        let out = format_exec_output_str(&exec);
+        let out = format_exec_output_str(&exec, &[]);
 
         // Strip truncation header if present for subsequent assertions
         let body = out
@@ -2461,7 +2929,7 @@ mod tests {
             timed_out: false,
         };
 
-        let out = format_exec_output_str(&exec);
+        let out = format_exec_output_str(&exec, &[]);
         // Keep strict budget on the truncated body (excluding header)
         let body = out
             .strip_prefix("Total output lines: ")
@@ -2496,12 +2964,38 @@ mod tests {
             duration: StdDuration::from_secs(1),
             timed_out: true,
         };
+        let command = vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "sleep 5 && echo done".to_string(),
+        ];
 
-        let out = format_exec_output_str(&exec);
+        let out = format_exec_output_str(&exec, &command);
 
         assert_eq!(
             out,
-            "command timed out after 1000 milliseconds\nCommand output"
+            "command timed out after 1000 milliseconds: bash -lc 'sleep 5 && echo done'\nCommand output"
+        );
+    }
+
+    #[test]
+    fn timed_out_message_truncates_long_commands() {
+        let exec = ExecToolCallOutput {
+            exit_code: 0,
+            stdout: StreamOutput::new(String::new()),
+            stderr: StreamOutput::new(String::new()),
+            aggregated_output: StreamOutput::new(String::new()),
+            duration: StdDuration::from_millis(250),
+            timed_out: true,
+        };
+        let command = vec!["echo".to_string(), "x".repeat(500)];
+
+        let out = format_exec_output_str(&exec, &command);
+
+        let expected_command = format!("echo {}", "x".repeat(195));
+        assert_eq!(
+            out,
+            format!("command timed out after 250 milliseconds: {expected_command}…\n")
         );
     }
 
@@ -2891,6 +3385,99 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn oversized_tool_arguments_are_rejected_before_dispatch() {
+        let (session, mut turn_context) = make_session_and_context();
+        turn_context.max_tool_arg_bytes = 16;
+        let session = Arc::new(session);
+        let turn_context = Arc::new(turn_context);
+        let router = ToolRouter::from_config(
+            &turn_context.tools_config,
+            Some(session.services.mcp_connection_manager.list_all_tools()),
+        );
+        let arguments = json!({ "command": ["echo", "x".repeat(64)] }).to_string();
+        let item = ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: arguments.clone(),
+            call_id: "call-1".to_string(),
+        };
+
+        let call = ToolRouter::build_tool_call(session.as_ref(), item)
+            .expect("build tool call")
+            .expect("tool call present");
+        let tracker = Arc::new(tokio::sync::Mutex::new(TurnDiffTracker::new()));
+        let response = router
+            .dispatch_tool_call(
+                Arc::clone(&session),
+                Arc::clone(&turn_context),
+                tracker,
+                call,
+            )
+            .await
+            .expect("oversized arguments should be reported to the model");
+
+        let expected = ResponseInputItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload {
+                content: format!(
+                    "arguments for shell are {} bytes, which exceeds the limit of 16 bytes; shorten the arguments (for example, split large content across several smaller calls) and try again",
+                    arguments.len()
+                ),
+                success: Some(false),
+            },
+        };
+        assert_eq!(expected, response);
+    }
+
+    #[tokio::test]
+    async fn record_assistant_message_updates_history_and_rollout() {
+        let (session, _turn_context) = make_session_and_context();
+        let codex_home = tempfile::tempdir().expect("create temp dir");
+        let config = Config::load_from_base_config_with_overrides(
+            ConfigToml::default(),
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect("load default test config");
+        let recorder = RolloutRecorder::new(
+            &config,
+            RolloutRecorderParams::new(session.conversation_id, None, SessionSource::Exec),
+        )
+        .await
+        .expect("create rollout recorder");
+        let rollout_path = recorder.get_rollout_path();
+        *session.services.rollout.lock().await = Some(recorder.clone());
+
+        session.record_assistant_message("replayed reply").await;
+
+        let expected = ResponseItem::Message {
+            id: None,
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText {
+                text: "replayed reply".to_string(),
+            }],
+        };
+        assert_eq!(vec![expected.clone()], session.history_snapshot().await);
+
+        recorder.flush().await.expect("flush rollout");
+        let InitialHistory::Resumed(resumed) = RolloutRecorder::get_rollout_history(&rollout_path)
+            .await
+            .expect("read rollout")
+        else {
+            panic!("expected resumed history");
+        };
+        let persisted: Vec<ResponseItem> = resumed
+            .history
+            .into_iter()
+            .filter_map(|item| match item {
+                RolloutItem::ResponseItem(item) => Some(item),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(vec![expected], persisted);
+    }
+
     fn sample_rollout(
         session: &Session,
         turn_context: &TurnContext,
@@ -2931,10 +3518,15 @@ mod tests {
             session.build_initial_context(turn_context),
             &user_messages1,
             summary1,
+            Some("2025-01-01T00:00:01Z"),
+            Some("test-model"),
         );
         live_history.replace(rebuilt1);
         rollout_items.push(RolloutItem::Compacted(CompactedItem {
             message: summary1.to_string(),
+            compacted_at: Some("2025-01-01T00:00:01Z".to_string()),
+            model: Some("test-model".to_string()),
+            replacement_history: None,
         }));
 
         let user2 = ResponseItem::Message {
@@ -2964,10 +3556,15 @@ mod tests {
             session.build_initial_context(turn_context),
             &user_messages2,
             summary2,
+            Some("2025-01-01T00:00:02Z"),
+            Some("test-model"),
         );
         live_history.replace(rebuilt2);
         rollout_items.push(RolloutItem::Compacted(CompactedItem {
             message: summary2.to_string(),
+            compacted_at: Some("2025-01-01T00:00:02Z".to_string()),
+            model: Some("test-model".to_string()),
+            replacement_history: None,
         }));
 
         let user3 = ResponseItem::Message {
@@ -3042,6 +3639,7 @@ mod tests {
         let resp = handle_container_exec_with_params(
             tool_name,
             params,
+            BTreeMap::new(),
             Arc::clone(&session),
             Arc::clone(&turn_context),
             Arc::clone(&turn_diff_tracker),
@@ -3069,6 +3667,7 @@ mod tests {
         let resp2 = handle_container_exec_with_params(
             tool_name,
             params2,
+            BTreeMap::new(),
             Arc::clone(&session),
             Arc::clone(&turn_context),
             Arc::clone(&turn_diff_tracker),
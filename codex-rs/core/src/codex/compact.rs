@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::Range;
 use std::sync::Arc;
 
 use super::Session;
@@ -5,20 +8,26 @@ use super::TurnContext;
 use super::get_last_assistant_message_from_turn;
 use crate::Prompt;
 use crate::client_common::ResponseEvent;
+use crate::conversation_history::ConversationHistory;
 use crate::error::CodexErr;
 use crate::error::Result as CodexResult;
 use crate::protocol::AgentMessageEvent;
 use crate::protocol::CompactedItem;
+use crate::protocol::CompactionConfigResponseEvent;
 use crate::protocol::ErrorEvent;
 use crate::protocol::EventMsg;
 use crate::protocol::TaskStartedEvent;
 use crate::protocol::TurnContextItem;
 use crate::state::TaskKind;
+use crate::token_estimate::estimate_text_tokens;
 use crate::truncate::truncate_middle;
 use crate::util::backoff;
 use askama::Template;
+use chrono::SecondsFormat;
+use chrono::Utc;
 use codex_protocol::items::TurnItem;
 use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::RolloutItem;
@@ -27,13 +36,51 @@ use futures::prelude::*;
 use tracing::error;
 
 pub const SUMMARIZATION_PROMPT: &str = include_str!("../../templates/compact/prompt.md");
+pub const TOOL_OUTPUT_SUMMARIZATION_PROMPT: &str =
+    include_str!("../../templates/compact/tool_output_prompt.md");
 const COMPACT_USER_MESSAGE_MAX_TOKENS: usize = 20_000;
+/// Tool outputs at or above this estimate are summarized by
+/// `run_tool_output_compact_task`.
+const TOOL_OUTPUT_COMPACT_MIN_TOKENS: usize = 2_000;
+/// Upper bound on how much of a single tool output is sent for summarization.
+const TOOL_OUTPUT_SUMMARY_INPUT_MAX_TOKENS: usize = 100_000;
+/// Opening line of `compact/history_bridge.md`, used to recognize the bridge
+/// message that `build_compacted_history` appends.
+const HISTORY_BRIDGE_PREFIX: &str =
+    "You were originally given instructions from a user over one or more turns.";
+/// Opening line of the message that `compact_range` splices in place of the
+/// items it summarized.
+const RANGE_SUMMARY_PREFIX: &str =
+    "Part of the earlier conversation was compacted. Here is a summary of the removed items:";
 
 #[derive(Template)]
 #[template(path = "compact/history_bridge.md", escape = "none")]
 struct HistoryBridgeTemplate<'a> {
     user_messages_text: &'a str,
     summary_text: &'a str,
+    provenance: Option<BridgeProvenance<'a>>,
+}
+
+/// When and by which model the bridged summary was produced.
+struct BridgeProvenance<'a> {
+    compacted_at: &'a str,
+    model: &'a str,
+}
+
+/// Retries allowed for a compaction request that fails to stream.
+fn compaction_max_retries(turn_context: &TurnContext) -> u64 {
+    turn_context
+        .compaction_max_retries
+        .unwrap_or_else(|| turn_context.client.get_provider().stream_max_retries())
+}
+
+/// Compaction settings in effect for turns run with `turn_context`.
+pub(crate) fn compaction_config(turn_context: &TurnContext) -> CompactionConfigResponseEvent {
+    CompactionConfigResponseEvent {
+        auto_compact_token_limit: turn_context.client.get_auto_compact_token_limit(),
+        model_context_window: turn_context.client.get_model_context_window(),
+        max_retries: compaction_max_retries(turn_context),
+    }
 }
 
 pub(crate) async fn run_inline_auto_compact_task(
@@ -69,11 +116,6 @@ async fn run_compact_task_inner(
     let mut history = sess.clone_history().await;
     history.record_items(&[initial_input_for_turn.into()]);
 
-    let mut truncated_count = 0usize;
-
-    let max_retries = turn_context.client.get_provider().stream_max_retries();
-    let mut retries = 0;
-
     let rollout_item = RolloutItem::TurnContext(TurnContextItem {
         cwd: turn_context.cwd.clone(),
         approval_policy: turn_context.approval_policy,
@@ -84,86 +126,448 @@ async fn run_compact_task_inner(
     });
     sess.persist_rollout_items(&[rollout_item]).await;
 
+    match stream_compaction_request(&sess, turn_context.as_ref(), history).await {
+        Ok(items) => sess.record_into_history(&items).await,
+        Err(CodexErr::Interrupted) => return,
+        Err(e) => {
+            if matches!(e, CodexErr::ContextWindowExceeded) {
+                sess.set_total_tokens_full(turn_context.as_ref()).await;
+            }
+            let event = EventMsg::Error(ErrorEvent {
+                message: e.to_string(),
+            });
+            sess.send_event(&turn_context, event).await;
+            return;
+        }
+    }
+
+    let summary_text = sess.last_assistant_message().await.unwrap_or_default();
+    let history_snapshot = sess.history_snapshot().await;
+    let user_messages = collect_user_messages(&history_snapshot);
+    let initial_context = sess.build_initial_context(turn_context.as_ref());
+    let compacted_at = Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true);
+    let model = turn_context.client.get_model();
+    let new_history = build_compacted_history(
+        initial_context,
+        &user_messages,
+        &summary_text,
+        Some(&compacted_at),
+        Some(&model),
+    );
+    sess.replace_compacted_history(new_history).await;
+
+    let rollout_item = RolloutItem::Compacted(CompactedItem {
+        message: summary_text.clone(),
+        compacted_at: Some(compacted_at),
+        model: Some(model),
+        replacement_history: None,
+    });
+    sess.persist_rollout_items(&[rollout_item]).await;
+
+    let event = EventMsg::AgentMessage(AgentMessageEvent {
+        message: "Compact task completed".to_string(),
+    });
+    sess.send_event(&turn_context, event).await;
+}
+
+/// Summarize each oversized tool output in the history and swap the summary in
+/// place of the original output, keeping the `call_id` pairing intact. Messages
+/// and smaller tool outputs are left untouched.
+pub(crate) async fn run_tool_output_compact_task(
+    sess: Arc<Session>,
+    turn_context: Arc<TurnContext>,
+) -> Option<String> {
+    let start_event = EventMsg::TaskStarted(TaskStartedEvent {
+        model_context_window: turn_context.client.get_model_context_window(),
+    });
+    sess.send_event(&turn_context, start_event).await;
+
+    // A full compaction already replaced every earlier tool output, so only
+    // the current segment can hold outputs worth summarizing.
+    let segment = sess.history_since_last_compaction().await;
+    let oversized = oversized_tool_outputs(&segment, TOOL_OUTPUT_COMPACT_MIN_TOKENS);
+    if oversized.is_empty() {
+        sess.notify_background_event(
+            turn_context.as_ref(),
+            "No tool outputs were large enough to summarize.",
+        )
+        .await;
+        return None;
+    }
+
+    let mut summaries = HashMap::new();
+    for tool_output in oversized {
+        match summarize_tool_output(&sess, turn_context.as_ref(), &tool_output).await {
+            Ok(summary) if !summary.is_empty() => {
+                summaries.insert(tool_output.call_id, summary);
+            }
+            Ok(_) => {}
+            Err(CodexErr::Interrupted) => return None,
+            Err(e) => {
+                let event = EventMsg::Error(ErrorEvent {
+                    message: e.to_string(),
+                });
+                sess.send_event(&turn_context, event).await;
+                return None;
+            }
+        }
+    }
+
+    let replacement_history = sess.summarize_tool_outputs_in_history(&summaries).await;
+    let message = format!("Summarized {} large tool output(s).", summaries.len());
+    let rollout_item = RolloutItem::Compacted(CompactedItem {
+        message: message.clone(),
+        compacted_at: None,
+        model: None,
+        replacement_history: Some(replacement_history),
+    });
+    sess.persist_rollout_items(&[rollout_item]).await;
+    sess.notify_background_event(turn_context.as_ref(), message)
+        .await;
+    None
+}
+
+/// A tool output large enough to summarize, with the call that produced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OversizedToolOutput {
+    pub(crate) call_id: String,
+    pub(crate) tool_name: String,
+    pub(crate) arguments: String,
+    pub(crate) output: String,
+}
+
+/// Summarize history items `[start, end)` in place; see `compact_range`.
+pub(crate) async fn run_range_compact_task(
+    sess: Arc<Session>,
+    turn_context: Arc<TurnContext>,
+    start: usize,
+    end: usize,
+) -> Option<String> {
+    let start_event = EventMsg::TaskStarted(TaskStartedEvent {
+        model_context_window: turn_context.client.get_model_context_window(),
+    });
+    sess.send_event(&turn_context, start_event).await;
+
+    match sess.compact_range(start, end, turn_context.as_ref()).await {
+        Ok(()) => {
+            sess.notify_background_event(
+                turn_context.as_ref(),
+                format!("Compacted history items {start}..{end}."),
+            )
+            .await;
+        }
+        Err(CodexErr::Interrupted) => {}
+        Err(e) => {
+            let event = EventMsg::Error(ErrorEvent {
+                message: e.to_string(),
+            });
+            sess.send_event(&turn_context, event).await;
+        }
+    }
+    None
+}
+
+/// Returns every tool output whose estimated size is at least `min_tokens`,
+/// along with the name and arguments of the call that produced it.
+pub(crate) fn oversized_tool_outputs(
+    items: &[ResponseItem],
+    min_tokens: usize,
+) -> Vec<OversizedToolOutput> {
+    let calls: HashMap<&str, (String, String)> = items
+        .iter()
+        .filter_map(|item| match item {
+            ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            } => Some((call_id.as_str(), (name.clone(), arguments.clone()))),
+            ResponseItem::CustomToolCall {
+                name,
+                input,
+                call_id,
+                ..
+            } => Some((call_id.as_str(), (name.clone(), input.clone()))),
+            ResponseItem::LocalShellCall {
+                call_id: Some(call_id),
+                action,
+                ..
+            } => Some((
+                call_id.as_str(),
+                (
+                    "local_shell".to_string(),
+                    serde_json::to_string(action).unwrap_or_default(),
+                ),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    items
+        .iter()
+        .filter_map(|item| match item {
+            ResponseItem::FunctionCallOutput { call_id, output } => {
+                Some((call_id, &output.content))
+            }
+            ResponseItem::CustomToolCallOutput { call_id, output } => Some((call_id, output)),
+            _ => None,
+        })
+        .filter(|(_, output)| estimate_text_tokens(output) >= min_tokens)
+        .filter_map(|(call_id, output)| {
+            // Normalized history pairs every output with its call.
+            let (tool_name, arguments) = calls.get(call_id.as_str())?.clone();
+            Some(OversizedToolOutput {
+                call_id: call_id.clone(),
+                tool_name,
+                arguments,
+                output: output.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Replace the outputs of the tool calls in `summaries` (keyed by `call_id`)
+/// with their summaries. All other items are returned unchanged.
+pub(crate) fn replace_tool_outputs(
+    items: Vec<ResponseItem>,
+    summaries: &HashMap<String, String>,
+) -> Vec<ResponseItem> {
+    items
+        .into_iter()
+        .map(|item| match item {
+            ResponseItem::FunctionCallOutput { call_id, output } => {
+                let output = match summaries.get(&call_id) {
+                    Some(summary) => FunctionCallOutputPayload {
+                        content: summarized_output_text(summary),
+                        success: output.success,
+                    },
+                    None => output,
+                };
+                ResponseItem::FunctionCallOutput { call_id, output }
+            }
+            ResponseItem::CustomToolCallOutput { call_id, output } => {
+                let output = summaries
+                    .get(&call_id)
+                    .map_or(output, |summary| summarized_output_text(summary));
+                ResponseItem::CustomToolCallOutput { call_id, output }
+            }
+            other => other,
+        })
+        .collect()
+}
+
+fn summarized_output_text(summary: &str) -> String {
+    format!("[output summarized to save context]\n{summary}")
+}
+
+async fn summarize_tool_output(
+    sess: &Session,
+    turn_context: &TurnContext,
+    tool_output: &OversizedToolOutput,
+) -> CodexResult<String> {
+    let input = vec![ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: tool_output_summary_request(tool_output),
+        }],
+    }];
+    summarize_input(sess, turn_context, input).await
+}
+
+/// The summarization request for `tool_output`: the prompt, the call that
+/// produced the output, and the (possibly truncated) output itself.
+fn tool_output_summary_request(tool_output: &OversizedToolOutput) -> String {
+    let OversizedToolOutput {
+        tool_name,
+        arguments,
+        output,
+        ..
+    } = tool_output;
+    let (output, _) = truncate_middle(output, TOOL_OUTPUT_SUMMARY_INPUT_MAX_TOKENS * 4);
+    format!(
+        "{TOOL_OUTPUT_SUMMARIZATION_PROMPT}\n\nTool: {tool_name}\nArguments: {arguments}\n\nOutput:\n{output}"
+    )
+}
+
+/// Summarize `items` with the compaction prompt and splice the summary back in
+/// their place. The range is widened so tool calls and their outputs are
+/// never split; everything outside it is left untouched.
+pub(crate) async fn compact_range(
+    sess: &Session,
+    turn_context: &TurnContext,
+    range: Range<usize>,
+) -> CodexResult<()> {
+    let history = sess.history_snapshot().await;
+    if range.start >= range.end || range.end > history.len() {
+        return Err(CodexErr::UnsupportedOperation(format!(
+            "invalid history range {}..{} for {} items",
+            range.start,
+            range.end,
+            history.len()
+        )));
+    }
+    let range = widen_range_to_call_pairs(&history, range);
+
+    let mut input = history[range.clone()].to_vec();
+    input.push(ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: SUMMARIZATION_PROMPT.to_string(),
+        }],
+    });
+    let summary = summarize_input(sess, turn_context, input).await?;
+
+    let expected = &history[range.clone()];
+    let replacement_history = sess
+        .splice_range_summary_in_history(range, expected, &summary)
+        .await?;
+    let rollout_item = RolloutItem::Compacted(CompactedItem {
+        message: summary,
+        compacted_at: None,
+        model: None,
+        replacement_history: Some(replacement_history),
+    });
+    sess.persist_rollout_items(&[rollout_item]).await;
+    Ok(())
+}
+
+/// Grow `range` until every tool call inside it has its output inside it too,
+/// and vice versa.
+pub(crate) fn widen_range_to_call_pairs(
+    items: &[ResponseItem],
+    mut range: Range<usize>,
+) -> Range<usize> {
+    loop {
+        let call_ids: HashSet<&str> = items[range.clone()]
+            .iter()
+            .filter_map(paired_call_id)
+            .collect();
+        let mut widened = range.clone();
+        for (idx, item) in items.iter().enumerate() {
+            if paired_call_id(item).is_some_and(|call_id| call_ids.contains(call_id)) {
+                widened.start = widened.start.min(idx);
+                widened.end = widened.end.max(idx + 1);
+            }
+        }
+        if widened == range {
+            return range;
+        }
+        range = widened;
+    }
+}
+
+/// Replace `items[range]` with a single user message carrying `summary`.
+pub(crate) fn splice_range_summary(
+    mut items: Vec<ResponseItem>,
+    range: Range<usize>,
+    summary: &str,
+) -> Vec<ResponseItem> {
+    let summary = if summary.is_empty() {
+        "(no summary available)"
+    } else {
+        summary
+    };
+    let message = ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputText {
+            text: format!("{RANGE_SUMMARY_PREFIX}\n\n{summary}"),
+        }],
+    };
+    items.splice(range, std::iter::once(message));
+    items
+}
+
+fn paired_call_id(item: &ResponseItem) -> Option<&str> {
+    match item {
+        ResponseItem::FunctionCall { call_id, .. }
+        | ResponseItem::FunctionCallOutput { call_id, .. }
+        | ResponseItem::CustomToolCall { call_id, .. }
+        | ResponseItem::CustomToolCallOutput { call_id, .. }
+        | ResponseItem::LocalShellCall {
+            call_id: Some(call_id),
+            ..
+        } => Some(call_id),
+        _ => None,
+    }
+}
+
+/// Stream `input` to the model without recording anything in the session
+/// history, and return the final assistant message.
+async fn summarize_input(
+    sess: &Session,
+    turn_context: &TurnContext,
+    input: Vec<ResponseItem>,
+) -> CodexResult<String> {
+    let mut history = ConversationHistory::new();
+    history.record_items(&input);
+    let items = stream_compaction_request(sess, turn_context, history).await?;
+    Ok(get_last_assistant_message_from_turn(&items).unwrap_or_default())
+}
+
+/// Stream a compaction request for `input` and return the items the model
+/// produced. Dropped streams are retried up to `compaction_max_retries`, and
+/// the oldest input items are trimmed while the prompt exceeds the context
+/// window.
+async fn stream_compaction_request(
+    sess: &Session,
+    turn_context: &TurnContext,
+    mut input: ConversationHistory,
+) -> CodexResult<Vec<ResponseItem>> {
+    let max_retries = compaction_max_retries(turn_context);
+    let mut retries = 0;
+    let mut truncated_count = 0usize;
     loop {
-        let turn_input = history.get_history();
+        let turn_input = input.get_history();
         let prompt = Prompt {
             input: turn_input.clone(),
             ..Default::default()
         };
-        let attempt_result = drain_to_completed(&sess, turn_context.as_ref(), &prompt).await;
-
-        match attempt_result {
-            Ok(()) => {
+        match collect_to_completed(sess, turn_context, &prompt).await {
+            Ok(items) => {
                 if truncated_count > 0 {
                     sess.notify_background_event(
-                        turn_context.as_ref(),
+                        turn_context,
                         format!(
                             "Trimmed {truncated_count} older conversation item(s) before compacting so the prompt fits the model context window."
                         ),
                     )
                     .await;
                 }
-                break;
-            }
-            Err(CodexErr::Interrupted) => {
-                return;
+                return Ok(items);
             }
+            Err(e @ CodexErr::Interrupted) => return Err(e),
             Err(e @ CodexErr::ContextWindowExceeded) => {
                 if turn_input.len() > 1 {
                     // Trim from the beginning to preserve cache (prefix-based) and keep recent messages intact.
                     error!(
                         "Context window exceeded while compacting; removing oldest history item. Error: {e}"
                     );
-                    history.remove_first_item();
+                    input.remove_first_item();
                     truncated_count += 1;
                     retries = 0;
                     continue;
                 }
-                sess.set_total_tokens_full(turn_context.as_ref()).await;
-                let event = EventMsg::Error(ErrorEvent {
-                    message: e.to_string(),
-                });
-                sess.send_event(&turn_context, event).await;
-                return;
+                return Err(e);
             }
             Err(e) => {
                 if retries < max_retries {
                     retries += 1;
                     let delay = backoff(retries);
                     sess.notify_stream_error(
-                        turn_context.as_ref(),
+                        turn_context,
                         format!("Re-connecting... {retries}/{max_retries}"),
                     )
                     .await;
                     tokio::time::sleep(delay).await;
                     continue;
-                } else {
-                    let event = EventMsg::Error(ErrorEvent {
-                        message: e.to_string(),
-                    });
-                    sess.send_event(&turn_context, event).await;
-                    return;
                 }
+                return Err(e);
             }
         }
     }
-
-    let history_snapshot = sess.history_snapshot().await;
-    let summary_text = get_last_assistant_message_from_turn(&history_snapshot).unwrap_or_default();
-    let user_messages = collect_user_messages(&history_snapshot);
-    let initial_context = sess.build_initial_context(turn_context.as_ref());
-    let new_history = build_compacted_history(initial_context, &user_messages, &summary_text);
-    sess.replace_history(new_history).await;
-
-    let rollout_item = RolloutItem::Compacted(CompactedItem {
-        message: summary_text.clone(),
-    });
-    sess.persist_rollout_items(&[rollout_item]).await;
-
-    let event = EventMsg::AgentMessage(AgentMessageEvent {
-        message: "Compact task completed".to_string(),
-    });
-    sess.send_event(&turn_context, event).await;
 }
 
 pub fn content_items_to_text(content: &[ContentItem]) -> Option<String> {
@@ -199,6 +603,8 @@ pub(crate) fn build_compacted_history(
     initial_context: Vec<ResponseItem>,
     user_messages: &[String],
     summary_text: &str,
+    compacted_at: Option<&str>,
+    model: Option<&str>,
 ) -> Vec<ResponseItem> {
     let mut history = initial_context;
     let mut user_messages_text = if user_messages.is_empty() {
@@ -208,8 +614,8 @@ pub(crate) fn build_compacted_history(
     };
     // Truncate the concatenated prior user messages so the bridge message
     // stays well under the context window (approx. 4 bytes/token).
-    let max_bytes = COMPACT_USER_MESSAGE_MAX_TOKENS * 4;
-    if user_messages_text.len() > max_bytes {
+    if estimate_text_tokens(&user_messages_text) > COMPACT_USER_MESSAGE_MAX_TOKENS {
+        let max_bytes = COMPACT_USER_MESSAGE_MAX_TOKENS * 4;
         user_messages_text = truncate_middle(&user_messages_text, max_bytes).0;
     }
     let summary_text = if summary_text.is_empty() {
@@ -217,9 +623,17 @@ pub(crate) fn build_compacted_history(
     } else {
         summary_text.to_string()
     };
+    // Summaries recorded before the rollout tracked provenance have none.
+    let provenance = compacted_at
+        .zip(model)
+        .map(|(compacted_at, model)| BridgeProvenance {
+            compacted_at,
+            model,
+        });
     let Ok(bridge) = HistoryBridgeTemplate {
         user_messages_text: &user_messages_text,
         summary_text: &summary_text,
+        provenance,
     }
     .render() else {
         return vec![];
@@ -232,16 +646,53 @@ pub(crate) fn build_compacted_history(
     history
 }
 
-async fn drain_to_completed(
+/// Returns true when `item` is the user message that bridges a compacted
+/// history to the turns that follow it.
+pub(crate) fn is_history_bridge_message(item: &ResponseItem) -> bool {
+    is_user_message_starting_with(item, HISTORY_BRIDGE_PREFIX)
+}
+
+/// Returns true when `item` is a summary that compaction put in the history:
+/// the history bridge or a summary spliced in by `compact_range`.
+pub(crate) fn is_compaction_summary_message(item: &ResponseItem) -> bool {
+    is_history_bridge_message(item) || is_user_message_starting_with(item, RANGE_SUMMARY_PREFIX)
+}
+
+fn is_user_message_starting_with(item: &ResponseItem, prefix: &str) -> bool {
+    match item {
+        ResponseItem::Message { role, content, .. } if role == "user" => {
+            content.iter().any(|content_item| match content_item {
+                ContentItem::InputText { text } => text.trim_start().starts_with(prefix),
+                _ => false,
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Items recorded after the most recent history bridge message. When the
+/// conversation has never been compacted, the whole history is returned.
+pub(crate) fn items_since_last_compaction(items: &[ResponseItem]) -> Vec<ResponseItem> {
+    let start = items
+        .iter()
+        .rposition(is_history_bridge_message)
+        .map_or(0, |idx| idx + 1);
+    items[start..].to_vec()
+}
+
+/// Stream `prompt` to the model without recording anything in the session
+/// history, and return the output items once the response completes.
+async fn collect_to_completed(
     sess: &Session,
     turn_context: &TurnContext,
     prompt: &Prompt,
-) -> CodexResult<()> {
+) -> CodexResult<Vec<ResponseItem>> {
     let mut stream = turn_context
         .client
         .clone()
         .stream_with_task_kind(prompt, TaskKind::Compact)
         .await?;
+    let mut items = Vec::new();
     loop {
         let maybe_event = stream.next().await;
         let Some(event) = maybe_event else {
@@ -251,16 +702,14 @@ async fn drain_to_completed(
             ));
         };
         match event {
-            Ok(ResponseEvent::OutputItemDone(item)) => {
-                sess.record_into_history(std::slice::from_ref(&item)).await;
-            }
+            Ok(ResponseEvent::OutputItemDone(item)) => items.push(item),
             Ok(ResponseEvent::RateLimits(snapshot)) => {
                 sess.update_rate_limits(turn_context, snapshot).await;
             }
             Ok(ResponseEvent::Completed { token_usage, .. }) => {
                 sess.update_token_usage_info(turn_context, token_usage.as_ref())
                     .await;
-                return Ok(());
+                return Ok(items);
             }
             Ok(_) => continue,
             Err(e) => return Err(e),
@@ -359,13 +808,134 @@ mod tests {
         assert_eq!(vec!["real user message".to_string()], collected);
     }
 
+    #[test]
+    fn replace_tool_outputs_only_summarizes_oversized_outputs() {
+        let user_message = ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: "run the tests".to_string(),
+            }],
+        };
+        let call = ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: r#"{"command":["cargo","test"]}"#.to_string(),
+            call_id: "call-big".to_string(),
+        };
+        let big_output = ResponseItem::FunctionCallOutput {
+            call_id: "call-big".to_string(),
+            output: FunctionCallOutputPayload {
+                content: "L".repeat(TOOL_OUTPUT_COMPACT_MIN_TOKENS * 4),
+                success: Some(false),
+            },
+        };
+        let small_output = ResponseItem::CustomToolCallOutput {
+            call_id: "call-small".to_string(),
+            output: "ok".to_string(),
+        };
+        let items = vec![
+            user_message.clone(),
+            call.clone(),
+            big_output,
+            small_output.clone(),
+        ];
+
+        let oversized = oversized_tool_outputs(&items, TOOL_OUTPUT_COMPACT_MIN_TOKENS);
+        assert_eq!(
+            vec![OversizedToolOutput {
+                call_id: "call-big".to_string(),
+                tool_name: "shell".to_string(),
+                arguments: r#"{"command":["cargo","test"]}"#.to_string(),
+                output: "L".repeat(TOOL_OUTPUT_COMPACT_MIN_TOKENS * 4),
+            }],
+            oversized
+        );
+        let request = tool_output_summary_request(&oversized[0]);
+        assert!(
+            request.contains("Tool: shell\nArguments: {\"command\":[\"cargo\",\"test\"]}\n"),
+            "summary request should name the call: {request}"
+        );
+
+        let summaries = HashMap::from([("call-big".to_string(), "2 tests failed".to_string())]);
+        let replaced = replace_tool_outputs(items, &summaries);
+
+        assert_eq!(
+            vec![
+                user_message,
+                call,
+                ResponseItem::FunctionCallOutput {
+                    call_id: "call-big".to_string(),
+                    output: FunctionCallOutputPayload {
+                        content: "[output summarized to save context]\n2 tests failed".to_string(),
+                        success: Some(false),
+                    },
+                },
+                small_output,
+            ],
+            replaced
+        );
+    }
+
+    #[test]
+    fn items_since_last_compaction_skips_through_latest_bridge() {
+        let user = |text: &str| ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        };
+        let assistant = ResponseItem::Message {
+            id: None,
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText {
+                text: "done".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            vec![user("first"), assistant.clone()],
+            items_since_last_compaction(&[user("first"), assistant.clone()])
+        );
+
+        let mut history = build_compacted_history(
+            vec![user("<user_instructions>be brief</user_instructions>")],
+            &["first".to_string()],
+            "FIRST SUMMARY",
+            Some("2025-01-01T00:00:00Z"),
+            Some("test-model"),
+        );
+        history.push(user("second"));
+        history = build_compacted_history(
+            history,
+            &["first".to_string(), "second".to_string()],
+            "SECOND SUMMARY",
+            Some("2025-01-01T00:01:00Z"),
+            Some("test-model"),
+        );
+        history.push(user("third"));
+        history.push(assistant.clone());
+
+        assert_eq!(
+            vec![user("third"), assistant],
+            items_since_last_compaction(&history)
+        );
+    }
+
     #[test]
     fn build_compacted_history_truncates_overlong_user_messages() {
         // Prepare a very large prior user message so the aggregated
         // `user_messages_text` exceeds the truncation threshold used by
         // `build_compacted_history` (80k bytes).
         let big = "X".repeat(200_000);
-        let history = build_compacted_history(Vec::new(), std::slice::from_ref(&big), "SUMMARY");
+        let history = build_compacted_history(
+            Vec::new(),
+            std::slice::from_ref(&big),
+            "SUMMARY",
+            Some("2025-01-01T00:00:00Z"),
+            Some("test-model"),
+        );
 
         // Expect exactly one bridge message added to history (plus any initial context we provided, which is none).
         assert_eq!(history.len(), 1);
@@ -392,4 +962,93 @@ mod tests {
             "bridge should include the provided summary text"
         );
     }
+
+    #[test]
+    fn build_compacted_history_records_provenance() {
+        let history = build_compacted_history(
+            Vec::new(),
+            &["fix the bug".to_string()],
+            "Fixed the bug.",
+            Some("2025-03-04T05:06:07Z"),
+            Some("gpt-5-codex"),
+        );
+
+        let [ResponseItem::Message { role, content, .. }] = history.as_slice() else {
+            panic!("expected a single bridge message, got {history:?}");
+        };
+        assert_eq!(role, "user");
+        let bridge_text = content_items_to_text(content).unwrap_or_default();
+        assert!(
+            bridge_text
+                .ends_with("This summary was produced by gpt-5-codex at 2025-03-04T05:06:07Z."),
+            "bridge should record the compaction time and model: {bridge_text}"
+        );
+        assert!(is_history_bridge_message(&history[0]));
+    }
+
+    #[test]
+    fn build_compacted_history_omits_unknown_provenance() {
+        let history = build_compacted_history(
+            Vec::new(),
+            &["fix the bug".to_string()],
+            "Fixed the bug.",
+            None,
+            None,
+        );
+
+        let [ResponseItem::Message { content, .. }] = history.as_slice() else {
+            panic!("expected a single bridge message, got {history:?}");
+        };
+        let bridge_text = content_items_to_text(content).unwrap_or_default();
+        assert!(
+            bridge_text.ends_with("Fixed the bug."),
+            "bridge without provenance should end with the summary: {bridge_text}"
+        );
+        assert!(!bridge_text.contains("This summary was produced by"));
+    }
+
+    #[test]
+    fn splice_range_summary_keeps_items_outside_range_and_call_pairs() {
+        let user = |text: &str| ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        };
+        let call = ResponseItem::FunctionCall {
+            id: None,
+            name: "shell".to_string(),
+            arguments: "{}".to_string(),
+            call_id: "call-1".to_string(),
+        };
+        let output = ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload {
+                content: "ok".to_string(),
+                success: Some(true),
+            },
+        };
+        let history = vec![
+            user("keep before"),
+            user("tangent"),
+            call,
+            output,
+            user("keep after"),
+        ];
+
+        // The requested range ends between the call and its output, so it is
+        // widened to take the output along.
+        let range = widen_range_to_call_pairs(&history, 1..3);
+        assert_eq!(1..4, range);
+
+        assert_eq!(
+            vec![
+                user("keep before"),
+                user(&format!("{RANGE_SUMMARY_PREFIX}\n\nChased a tangent.")),
+                user("keep after"),
+            ],
+            splice_range_summary(history, range, "Chased a tangent.")
+        );
+    }
 }
@@ -51,6 +51,7 @@ use std::collections::HashMap;
 use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use tempfile::NamedTempFile;
 use toml::Value as TomlValue;
@@ -70,6 +71,7 @@ pub const GPT_5_CODEX_MEDIUM_MODEL: &str = "gpt-5-codex";
 /// files are *silently truncated* to this size so we do not take up too much of
 /// the context window.
 pub(crate) const PROJECT_DOC_MAX_BYTES: usize = 32 * 1024; // 32 KiB
+pub(crate) const DEFAULT_MAX_TOOL_ARG_BYTES: usize = 1024 * 1024; // 1 MiB
 
 pub(crate) const CONFIG_TOML_FILE: &str = "config.toml";
 
@@ -93,6 +95,10 @@ pub struct Config {
     /// Token usage threshold triggering auto-compaction of conversation history.
     pub model_auto_compact_token_limit: Option<i64>,
 
+    /// Retry budget for compaction requests. Falls back to the provider's
+    /// `stream_max_retries` when unset.
+    pub compaction_max_retries: Option<u64>,
+
     /// Key into the model_providers map that specifies which provider to use.
     pub model_provider_id: String,
 
@@ -151,6 +157,10 @@ pub struct Config {
     /// and turn completions when not focused.
     pub tui_notifications: Notifications,
 
+    /// When set, the TUI footer rotates discoverability tips once the composer
+    /// has been idle for this long.
+    pub tui_idle_tip_delay: Option<Duration>,
+
     /// The directory that should be treated as the current working directory
     /// for the session. All relative paths inside the business-logic layer are
     /// resolved against this path.
@@ -174,6 +184,10 @@ pub struct Config {
     /// Maximum number of bytes to include from an AGENTS.md project doc file.
     pub project_doc_max_bytes: usize,
 
+    /// Tool calls whose arguments exceed this many bytes are rejected before
+    /// being dispatched, and the model is asked to shorten them.
+    pub max_tool_arg_bytes: usize,
+
     /// Additional filenames to try when looking for project-level docs.
     pub project_doc_fallback_filenames: Vec<String>,
 
@@ -839,6 +853,10 @@ pub struct ConfigToml {
     /// Token usage threshold triggering auto-compaction of conversation history.
     pub model_auto_compact_token_limit: Option<i64>,
 
+    /// Retry budget for compaction requests, independent of the provider's
+    /// `stream_max_retries`.
+    pub compaction_max_retries: Option<u64>,
+
     /// Default approval policy for executing commands.
     pub approval_policy: Option<AskForApproval>,
 
@@ -885,6 +903,9 @@ pub struct ConfigToml {
     /// Maximum number of bytes to include from an AGENTS.md project doc file.
     pub project_doc_max_bytes: Option<usize>,
 
+    /// Maximum size in bytes of the arguments of a single tool call.
+    pub max_tool_arg_bytes: Option<usize>,
+
     /// Ordered list of fallback filenames to look for when AGENTS.md is missing.
     pub project_doc_fallback_filenames: Option<Vec<String>>,
 
@@ -1335,6 +1356,7 @@ impl Config {
             model_context_window,
             model_max_output_tokens,
             model_auto_compact_token_limit,
+            compaction_max_retries: cfg.compaction_max_retries,
             model_provider_id,
             model_provider,
             cwd: resolved_cwd,
@@ -1351,6 +1373,7 @@ impl Config {
             mcp_oauth_credentials_store_mode: cfg.mcp_oauth_credentials_store.unwrap_or_default(),
             model_providers,
             project_doc_max_bytes: cfg.project_doc_max_bytes.unwrap_or(PROJECT_DOC_MAX_BYTES),
+            max_tool_arg_bytes: cfg.max_tool_arg_bytes.unwrap_or(DEFAULT_MAX_TOOL_ARG_BYTES),
             project_doc_fallback_filenames: cfg
                 .project_doc_fallback_filenames
                 .unwrap_or_default()
@@ -1405,6 +1428,11 @@ impl Config {
                 .as_ref()
                 .map(|t| t.notifications.clone())
                 .unwrap_or_default(),
+            tui_idle_tip_delay: cfg
+                .tui
+                .as_ref()
+                .and_then(|t| t.idle_tip_delay_secs)
+                .map(Duration::from_secs),
             otel: {
                 let t: OtelConfigToml = cfg.otel.unwrap_or_default();
                 let log_user_prompt = t.log_user_prompt.unwrap_or(false);
@@ -1532,7 +1560,6 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    use std::time::Duration;
     use tempfile::TempDir;
 
     #[test]
@@ -2844,6 +2871,7 @@ model_verbosity = "high"
                 model_context_window: Some(200_000),
                 model_max_output_tokens: Some(100_000),
                 model_auto_compact_token_limit: Some(180_000),
+                compaction_max_retries: None,
                 model_provider_id: "openai".to_string(),
                 model_provider: fixture.openai_provider.clone(),
                 approval_policy: AskForApproval::Never,
@@ -2857,6 +2885,7 @@ model_verbosity = "high"
                 mcp_oauth_credentials_store_mode: Default::default(),
                 model_providers: fixture.model_provider_map.clone(),
                 project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
+                max_tool_arg_bytes: DEFAULT_MAX_TOOL_ARG_BYTES,
                 project_doc_fallback_filenames: Vec::new(),
                 codex_home: fixture.codex_home(),
                 history: History::default(),
@@ -2884,6 +2913,7 @@ model_verbosity = "high"
                 notices: Default::default(),
                 disable_paste_burst: false,
                 tui_notifications: Default::default(),
+                tui_idle_tip_delay: None,
                 otel: OtelConfig::default(),
             },
             o3_profile_config
@@ -2912,6 +2942,7 @@ model_verbosity = "high"
             model_context_window: Some(16_385),
             model_max_output_tokens: Some(4_096),
             model_auto_compact_token_limit: Some(14_746),
+            compaction_max_retries: None,
             model_provider_id: "openai-chat-completions".to_string(),
             model_provider: fixture.openai_chat_completions_provider.clone(),
             approval_policy: AskForApproval::UnlessTrusted,
@@ -2925,6 +2956,7 @@ model_verbosity = "high"
             mcp_oauth_credentials_store_mode: Default::default(),
             model_providers: fixture.model_provider_map.clone(),
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
+            max_tool_arg_bytes: DEFAULT_MAX_TOOL_ARG_BYTES,
             project_doc_fallback_filenames: Vec::new(),
             codex_home: fixture.codex_home(),
             history: History::default(),
@@ -2952,6 +2984,7 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_idle_tip_delay: None,
             otel: OtelConfig::default(),
         };
 
@@ -2995,6 +3028,7 @@ model_verbosity = "high"
             model_context_window: Some(200_000),
             model_max_output_tokens: Some(100_000),
             model_auto_compact_token_limit: Some(180_000),
+            compaction_max_retries: None,
             model_provider_id: "openai".to_string(),
             model_provider: fixture.openai_provider.clone(),
             approval_policy: AskForApproval::OnFailure,
@@ -3008,6 +3042,7 @@ model_verbosity = "high"
             mcp_oauth_credentials_store_mode: Default::default(),
             model_providers: fixture.model_provider_map.clone(),
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
+            max_tool_arg_bytes: DEFAULT_MAX_TOOL_ARG_BYTES,
             project_doc_fallback_filenames: Vec::new(),
             codex_home: fixture.codex_home(),
             history: History::default(),
@@ -3035,6 +3070,7 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_idle_tip_delay: None,
             otel: OtelConfig::default(),
         };
 
@@ -3064,6 +3100,7 @@ model_verbosity = "high"
             model_context_window: Some(272_000),
             model_max_output_tokens: Some(128_000),
             model_auto_compact_token_limit: Some(244_800),
+            compaction_max_retries: None,
             model_provider_id: "openai".to_string(),
             model_provider: fixture.openai_provider.clone(),
             approval_policy: AskForApproval::OnFailure,
@@ -3077,6 +3114,7 @@ model_verbosity = "high"
             mcp_oauth_credentials_store_mode: Default::default(),
             model_providers: fixture.model_provider_map.clone(),
             project_doc_max_bytes: PROJECT_DOC_MAX_BYTES,
+            max_tool_arg_bytes: DEFAULT_MAX_TOOL_ARG_BYTES,
             project_doc_fallback_filenames: Vec::new(),
             codex_home: fixture.codex_home(),
             history: History::default(),
@@ -3104,6 +3142,7 @@ model_verbosity = "high"
             notices: Default::default(),
             disable_paste_burst: false,
             tui_notifications: Default::default(),
+            tui_idle_tip_delay: None,
             otel: OtelConfig::default(),
         };
 
@@ -3253,6 +3292,19 @@ mod notifications_tests {
         assert_matches!(parsed.tui.notifications, Notifications::Enabled(true));
     }
 
+    #[test]
+    fn test_tui_idle_tip_delay() {
+        let toml = r#"
+            [tui]
+            idle_tip_delay_secs = 45
+        "#;
+        let parsed: ConfigToml = toml::from_str(toml).expect("deserialize idle_tip_delay_secs");
+        assert_eq!(
+            parsed.tui.expect("tui section").idle_tip_delay_secs,
+            Some(45)
+        );
+    }
+
     #[test]
     fn test_tui_notifications_custom_array() {
         let toml = r#"
@@ -349,6 +349,11 @@ pub struct Tui {
     /// Defaults to `false`.
     #[serde(default)]
     pub notifications: Notifications,
+
+    /// Show rotating tips in the footer after the composer has been idle for
+    /// this many seconds. Unset (the default) disables tips.
+    #[serde(default)]
+    pub idle_tip_delay_secs: Option<u64>,
 }
 
 /// Settings for notices we display to users via the tui and app-server clients
@@ -2,6 +2,10 @@ use codex_protocol::models::FunctionCallOutputPayload;
 use codex_protocol::models::ResponseItem;
 use tracing::error;
 
+/// Output recorded for tool calls whose result never made it into the rollout,
+/// e.g. because the process exited mid-turn.
+pub(crate) const INTERRUPTED_CALL_OUTPUT: &str = "(interrupted; no output recorded)";
+
 /// Transcript of conversation history
 #[derive(Debug, Clone, Default)]
 pub(crate) struct ConversationHistory {
@@ -46,6 +50,13 @@ impl ConversationHistory {
         }
     }
 
+    /// Give every call without an output a placeholder output so a history
+    /// reconstructed from a rollout that was cut off mid-turn is valid to send
+    /// back to the model.
+    pub(crate) fn repair_interrupted_tool_calls(&mut self) {
+        self.insert_missing_call_outputs(INTERRUPTED_CALL_OUTPUT, false);
+    }
+
     /// This function enforces a couple of invariants on the in-memory history:
     /// 1. every call (function/custom) has a corresponding output entry
     /// 2. every output has a corresponding call entry
@@ -63,6 +74,12 @@ impl ConversationHistory {
     }
 
     fn ensure_call_outputs_present(&mut self) {
+        self.insert_missing_call_outputs("aborted", true);
+    }
+
+    /// Insert `placeholder` as the output of every call that lacks one. When
+    /// `report_missing` is set, a missing output is treated as a bug.
+    fn insert_missing_call_outputs(&mut self, placeholder: &str, report_missing: bool) {
         // Collect synthetic outputs to insert immediately after their calls.
         // Store the insertion position (index of call) alongside the item so
         // we can insert in reverse order and avoid index shifting.
@@ -79,15 +96,17 @@ impl ConversationHistory {
                     });
 
                     if !has_output {
-                        error_or_panic(format!(
-                            "Function call output is missing for call id: {call_id}"
-                        ));
+                        if report_missing {
+                            error_or_panic(format!(
+                                "Function call output is missing for call id: {call_id}"
+                            ));
+                        }
                         missing_outputs_to_insert.push((
                             idx,
                             ResponseItem::FunctionCallOutput {
                                 call_id: call_id.clone(),
                                 output: FunctionCallOutputPayload {
-                                    content: "aborted".to_string(),
+                                    content: placeholder.to_string(),
                                     success: None,
                                 },
                             },
@@ -103,14 +122,16 @@ impl ConversationHistory {
                     });
 
                     if !has_output {
-                        error_or_panic(format!(
-                            "Custom tool call output is missing for call id: {call_id}"
-                        ));
+                        if report_missing {
+                            error_or_panic(format!(
+                                "Custom tool call output is missing for call id: {call_id}"
+                            ));
+                        }
                         missing_outputs_to_insert.push((
                             idx,
                             ResponseItem::CustomToolCallOutput {
                                 call_id: call_id.clone(),
-                                output: "aborted".to_string(),
+                                output: placeholder.to_string(),
                             },
                         ));
                     }
@@ -126,15 +147,17 @@ impl ConversationHistory {
                         });
 
                         if !has_output {
-                            error_or_panic(format!(
-                                "Local shell call output is missing for call id: {call_id}"
-                            ));
+                            if report_missing {
+                                error_or_panic(format!(
+                                    "Local shell call output is missing for call id: {call_id}"
+                                ));
+                            }
                             missing_outputs_to_insert.push((
                                 idx,
                                 ResponseItem::FunctionCallOutput {
                                     call_id: call_id.clone(),
                                     output: FunctionCallOutputPayload {
-                                        content: "aborted".to_string(),
+                                        content: placeholder.to_string(),
                                         success: None,
                                     },
                                 },
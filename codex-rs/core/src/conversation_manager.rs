@@ -11,6 +11,8 @@ use crate::protocol::Event;
 use crate::protocol::EventMsg;
 use crate::protocol::SessionConfiguredEvent;
 use crate::rollout::RolloutRecorder;
+use crate::rollout::list::RolloutSummary;
+use crate::rollout::list::list_rollout_summaries;
 use codex_protocol::ConversationId;
 use codex_protocol::items::TurnItem;
 use codex_protocol::models::ResponseItem;
@@ -18,6 +20,7 @@ use codex_protocol::protocol::InitialHistory;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::SessionSource;
 use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -136,6 +139,23 @@ impl ConversationManager {
         self.finalize_spawn(codex, conversation_id).await
     }
 
+    /// List up to `limit` rollout files under `codex_home` that were recorded
+    /// by this manager's session source, newest first, each with a one-line
+    /// preview of its first user message.
+    pub async fn list_rollouts(
+        &self,
+        codex_home: &Path,
+        limit: usize,
+    ) -> CodexResult<Vec<RolloutSummary>> {
+        let summaries = list_rollout_summaries(
+            codex_home,
+            limit,
+            std::slice::from_ref(&self.session_source),
+        )
+        .await?;
+        Ok(summaries)
+    }
+
     /// Removes the conversation from the manager's internal map, though the
     /// conversation is stored as `Arc<CodexConversation>`, it is possible that
     /// other references to it exist elsewhere. Returns the conversation if the
@@ -39,6 +39,10 @@ pub enum Feature {
     ViewImageTool,
     /// Allow the model to request web searches.
     WebSearchRequest,
+    /// Include the context_budget tool so the model can check its remaining context.
+    ContextBudgetTool,
+    /// Send exec stdout and stderr to the model as separately labeled sections.
+    LabeledExecOutput,
 }
 
 impl Feature {
@@ -236,4 +240,16 @@ pub const FEATURES: &[FeatureSpec] = &[
         stage: Stage::Stable,
         default_enabled: false,
     },
+    FeatureSpec {
+        id: Feature::ContextBudgetTool,
+        key: "context_budget_tool",
+        stage: Stage::Experimental,
+        default_enabled: false,
+    },
+    FeatureSpec {
+        id: Feature::LabeledExecOutput,
+        key: "labeled_exec_output",
+        stage: Stage::Experimental,
+        default_enabled: false,
+    },
 ];
@@ -585,6 +585,73 @@ pub async fn current_branch_name(cwd: &Path) -> Option<String> {
         .filter(|name| !name.is_empty())
 }
 
+/// Resolve a ref for the repository's default branch that exists locally, so
+/// it can be used as a diff base without contacting any remote.
+///
+/// Preference order:
+/// 1) The remote-tracking branch `refs/remotes/<remote>/HEAD` points at (origin prioritized)
+/// 2) The upstream of a local `main` or `master` branch
+/// 3) The local `main` or `master` branch itself
+pub async fn default_branch_base_ref(cwd: &Path) -> Option<String> {
+    let remotes = get_git_remotes(cwd).await.unwrap_or_default();
+    for remote in remotes {
+        let Some(output) = run_git_command_with_timeout(
+            &[
+                "symbolic-ref",
+                "--quiet",
+                "--short",
+                &format!("refs/remotes/{remote}/HEAD"),
+            ],
+            cwd,
+        )
+        .await
+        else {
+            continue;
+        };
+        if !output.status.success() {
+            continue;
+        }
+        let Ok(text) = String::from_utf8(output.stdout) else {
+            continue;
+        };
+        let tracking = text.trim();
+        // The symbolic ref can outlive the branch it points at.
+        if let Some(verify) = run_git_command_with_timeout(
+            &[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{tracking}^{{commit}}"),
+            ],
+            cwd,
+        )
+        .await
+            && verify.status.success()
+        {
+            return Some(tracking.to_string());
+        }
+    }
+
+    let local = get_default_branch_local(cwd).await?;
+    if let Some(output) = run_git_command_with_timeout(
+        &[
+            "rev-parse",
+            "--abbrev-ref",
+            "--symbolic-full-name",
+            &format!("{local}@{{upstream}}"),
+        ],
+        cwd,
+    )
+    .await
+        && output.status.success()
+        && let Ok(text) = String::from_utf8(output.stdout)
+        && !text.trim().is_empty()
+    {
+        return Some(text.trim().to_string());
+    }
+    Some(local)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -762,6 +829,40 @@ mod tests {
         (repo_path, branch)
     }
 
+    #[tokio::test]
+    async fn default_branch_base_ref_prefers_remote_tracking_branch() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let (repo_path, branch) = create_test_git_repo_with_remote(&temp_dir).await;
+
+        // Without `origin/HEAD`, the local default branch's upstream is used.
+        assert_eq!(
+            Some(format!("origin/{branch}")),
+            default_branch_base_ref(&repo_path).await
+        );
+
+        Command::new("git")
+            .args(["remote", "set-head", "origin", &branch])
+            .current_dir(&repo_path)
+            .output()
+            .await
+            .expect("Failed to set origin/HEAD");
+        assert_eq!(
+            Some(format!("origin/{branch}")),
+            default_branch_base_ref(&repo_path).await
+        );
+    }
+
+    #[tokio::test]
+    async fn default_branch_base_ref_falls_back_to_local_branch() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let repo_path = create_test_git_repo(&temp_dir).await;
+
+        let base = default_branch_base_ref(&repo_path)
+            .await
+            .expect("local default branch");
+        assert!(base == "main" || base == "master");
+    }
+
     #[tokio::test]
     async fn test_collect_git_info_non_git_directory() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -0,0 +1,179 @@
+//! Compare two history snapshots, e.g. before and after compaction, to see
+//! which items were dropped, introduced, or carried over.
+
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::models::WebSearchAction;
+
+use crate::codex::compact::content_items_to_text;
+
+/// Longest text preview kept in an item summary.
+const SUMMARY_PREVIEW_CHARS: usize = 60;
+
+/// One-line summaries of the items that differ between two snapshots. Items
+/// are compared by value; duplicates are matched one-for-one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryDiff {
+    /// Items only present in `before`, in their original order.
+    pub removed: Vec<String>,
+    /// Items only present in `after`, in their original order.
+    pub added: Vec<String>,
+    /// Items present in both snapshots, in `after` order.
+    pub retained: Vec<String>,
+}
+
+pub fn history_diff(before: &[ResponseItem], after: &[ResponseItem]) -> HistoryDiff {
+    let mut unmatched: Vec<bool> = vec![true; before.len()];
+    let mut diff = HistoryDiff::default();
+
+    for item in after {
+        let matched = before
+            .iter()
+            .enumerate()
+            .position(|(idx, candidate)| unmatched[idx] && candidate == item);
+        match matched {
+            Some(idx) => {
+                unmatched[idx] = false;
+                diff.retained.push(summarize_item(item));
+            }
+            None => diff.added.push(summarize_item(item)),
+        }
+    }
+
+    diff.removed = before
+        .iter()
+        .zip(unmatched)
+        .filter(|(_, unmatched)| *unmatched)
+        .map(|(item, _)| summarize_item(item))
+        .collect();
+    diff
+}
+
+fn summarize_item(item: &ResponseItem) -> String {
+    match item {
+        ResponseItem::Message { role, content, .. } => {
+            let text = content_items_to_text(content).unwrap_or_default();
+            format!("{role}: {}", preview(&text))
+        }
+        ResponseItem::Reasoning { .. } => "reasoning".to_string(),
+        ResponseItem::FunctionCall { name, call_id, .. } => {
+            format!("function_call {name} ({call_id})")
+        }
+        ResponseItem::FunctionCallOutput { call_id, output } => {
+            format!(
+                "function_call_output ({call_id}): {}",
+                preview(&output.content)
+            )
+        }
+        ResponseItem::CustomToolCall { name, call_id, .. } => {
+            format!("custom_tool_call {name} ({call_id})")
+        }
+        ResponseItem::CustomToolCallOutput { call_id, output } => {
+            format!("custom_tool_call_output ({call_id}): {}", preview(output))
+        }
+        ResponseItem::LocalShellCall { action, .. } => match action {
+            LocalShellAction::Exec(exec) => {
+                format!("local_shell_call: {}", preview(&exec.command.join(" ")))
+            }
+        },
+        ResponseItem::WebSearchCall { action, .. } => match action {
+            WebSearchAction::Search { query } => format!("web_search_call: {}", preview(query)),
+            WebSearchAction::Other => "web_search_call".to_string(),
+        },
+        ResponseItem::Other => "other".to_string(),
+    }
+}
+
+/// First line of `text`, shortened to `SUMMARY_PREVIEW_CHARS` characters.
+fn preview(text: &str) -> String {
+    let first_line = text.lines().next().unwrap_or_default();
+    if first_line.chars().count() > SUMMARY_PREVIEW_CHARS || text.lines().nth(1).is_some() {
+        let shortened: String = first_line.chars().take(SUMMARY_PREVIEW_CHARS).collect();
+        format!("{shortened}…")
+    } else {
+        first_line.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codex::compact::build_compacted_history;
+    use codex_protocol::models::ContentItem;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn message(role: &str, text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn compaction_removes_turns_and_adds_bridge() {
+        let instructions = message("user", "<user_instructions>be brief</user_instructions>");
+        let before = vec![
+            instructions.clone(),
+            message("user", "run the tests"),
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["cargo","test"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload {
+                    content: "test result: ok".to_string(),
+                    success: Some(true),
+                },
+            },
+            message("assistant", "All tests pass."),
+        ];
+        let after = build_compacted_history(
+            vec![instructions],
+            &["run the tests".to_string()],
+            "Ran the tests.",
+            Some("2025-01-01T00:00:00Z"),
+            Some("test-model"),
+        );
+
+        let diff = history_diff(&before, &after);
+
+        assert_eq!(
+            HistoryDiff {
+                removed: vec![
+                    "user: run the tests".to_string(),
+                    "function_call shell (call-1)".to_string(),
+                    "function_call_output (call-1): test result: ok".to_string(),
+                    "assistant: All tests pass.".to_string(),
+                ],
+                added: vec![
+                    "user: You were originally given instructions from a user over one …"
+                        .to_string()
+                ],
+                retained: vec!["user: <user_instructions>be brief</user_instructions>".to_string()],
+            },
+            diff
+        );
+    }
+
+    #[test]
+    fn duplicates_are_matched_one_for_one() {
+        let before = vec![message("user", "again")];
+        let after = vec![message("user", "again"), message("user", "again")];
+
+        assert_eq!(
+            HistoryDiff {
+                removed: Vec::new(),
+                added: vec!["user: again".to_string()],
+                retained: vec!["user: again".to_string()],
+            },
+            history_diff(&before, &after)
+        );
+    }
+}
@@ -29,6 +29,7 @@ pub mod exec_env;
 pub mod features;
 mod flags;
 pub mod git_info;
+pub mod history_diff;
 pub mod landlock;
 pub mod mcp;
 mod mcp_connection_manager;
@@ -38,6 +39,8 @@ mod model_provider_info;
 pub mod parse_command;
 pub mod sandboxing;
 pub mod token_data;
+pub mod token_estimate;
+mod transcript_export;
 mod truncate;
 mod unified_exec;
 mod user_instructions;
@@ -76,6 +79,7 @@ pub use rollout::find_conversation_path_by_id_str;
 pub use rollout::list::ConversationItem;
 pub use rollout::list::ConversationsPage;
 pub use rollout::list::Cursor;
+pub use rollout::list::RolloutSummary;
 mod function_tool;
 mod state;
 mod tasks;
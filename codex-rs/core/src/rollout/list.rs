@@ -14,9 +14,14 @@ use time::macros::format_description;
 use uuid::Uuid;
 
 use super::SESSIONS_SUBDIR;
+use crate::event_mapping::parse_turn_item;
 use crate::protocol::EventMsg;
+use codex_protocol::ConversationId;
+use codex_protocol::items::TurnItem;
+use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::RolloutLine;
+use codex_protocol::protocol::SessionMeta;
 use codex_protocol::protocol::SessionSource;
 
 /// Returned page of conversation summaries.
@@ -47,6 +52,48 @@ pub struct ConversationItem {
     pub updated_at: Option<String>,
 }
 
+/// Condensed view of a rollout file, suitable for offering "resume a previous
+/// session" choices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RolloutSummary {
+    /// Absolute path to the rollout file.
+    pub path: PathBuf,
+    pub conversation_id: ConversationId,
+    /// RFC3339 timestamp string for when the session was created, if available.
+    pub created_at: Option<String>,
+    /// First line of the first user message, if the session has one.
+    pub preview: Option<String>,
+}
+
+impl RolloutSummary {
+    /// Build a summary from a listed conversation. Returns `None` when the head
+    /// does not start with a readable session meta record.
+    pub fn from_conversation_item(item: &ConversationItem) -> Option<Self> {
+        let meta = serde_json::from_value::<SessionMeta>(item.head.first()?.clone()).ok()?;
+        let preview = item
+            .head
+            .iter()
+            .filter_map(|value| serde_json::from_value::<ResponseItem>(value.clone()).ok())
+            .find_map(|item| match parse_turn_item(&item) {
+                Some(TurnItem::UserMessage(user)) => Some(user.message()),
+                _ => None,
+            })
+            .and_then(|message| {
+                message
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(str::to_string)
+            });
+        Some(Self {
+            path: item.path.clone(),
+            conversation_id: meta.id,
+            created_at: item.created_at.clone(),
+            preview,
+        })
+    }
+}
+
 #[derive(Default)]
 struct HeadTailSummary {
     head: Vec<serde_json::Value>,
@@ -129,6 +176,21 @@ pub(crate) async fn get_conversations(
     Ok(result)
 }
 
+/// List up to `limit` rollout files under `codex_home`, newest first, as
+/// [`RolloutSummary`] values. Files without a readable session meta are skipped.
+pub(crate) async fn list_rollout_summaries(
+    codex_home: &Path,
+    limit: usize,
+    allowed_sources: &[SessionSource],
+) -> io::Result<Vec<RolloutSummary>> {
+    let page = get_conversations(codex_home, limit, None, allowed_sources).await?;
+    Ok(page
+        .items
+        .iter()
+        .filter_map(RolloutSummary::from_conversation_item)
+        .collect())
+}
+
 /// Load the full contents of a single conversation session file at `path`.
 /// Returns the entire file contents as a String.
 #[allow(dead_code)]
@@ -68,6 +68,10 @@ pub(crate) fn should_persist_event_msg(ev: &EventMsg) -> bool {
         | EventMsg::GetHistoryEntryResponse(_)
         | EventMsg::McpListToolsResponse(_)
         | EventMsg::ListCustomPromptsResponse(_)
+        | EventMsg::CompactionStatsResponse(_)
+        | EventMsg::CompactionConfigResponse(_)
+        | EventMsg::TurnContextChanged(_)
+        | EventMsg::ReasoningEffortResponse(_)
         | EventMsg::PlanUpdate(_)
         | EventMsg::ShutdownComplete
         | EventMsg::ViewImageToolCall(_)
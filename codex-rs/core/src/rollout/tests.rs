@@ -1,6 +1,7 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
 use std::fs::File;
+use std::fs::OpenOptions;
 use std::fs::{self};
 use std::io::Write;
 use std::path::Path;
@@ -16,8 +17,10 @@ use crate::rollout::INTERACTIVE_SESSION_SOURCES;
 use crate::rollout::list::ConversationItem;
 use crate::rollout::list::ConversationsPage;
 use crate::rollout::list::Cursor;
+use crate::rollout::list::RolloutSummary;
 use crate::rollout::list::get_conversation;
 use crate::rollout::list::get_conversations;
+use crate::rollout::list::list_rollout_summaries;
 use anyhow::Result;
 use codex_protocol::ConversationId;
 use codex_protocol::models::ContentItem;
@@ -732,6 +735,9 @@ async fn test_tail_skips_trailing_non_responses() -> Result<()> {
         timestamp: format!("{ts}-compacted"),
         item: RolloutItem::Compacted(CompactedItem {
             message: "compacted".into(),
+            compacted_at: None,
+            model: None,
+            replacement_history: None,
         }),
     };
     writeln!(file, "{}", serde_json::to_string(&compacted_line)?)?;
@@ -921,3 +927,70 @@ async fn test_source_filter_excludes_non_matching_sessions() {
         path.ends_with("rollout-2025-08-01T10-00-00-00000000-0000-0000-0000-00000000004d.jsonl")
     }));
 }
+
+#[tokio::test]
+async fn test_list_rollout_summaries_include_previews_newest_first() {
+    let temp = TempDir::new().unwrap();
+    let home = temp.path();
+
+    let older_id = Uuid::from_u128(11);
+    let newer_id = Uuid::from_u128(12);
+    write_session_file(
+        home,
+        "2025-01-01T12-00-00",
+        older_id,
+        0,
+        Some(SessionSource::Cli),
+    )
+    .unwrap();
+    write_session_file(
+        home,
+        "2025-01-02T12-00-00",
+        newer_id,
+        0,
+        Some(SessionSource::Cli),
+    )
+    .unwrap();
+
+    let older_path = home
+        .join("sessions/2025/01/01")
+        .join(format!("rollout-2025-01-01T12-00-00-{older_id}.jsonl"));
+    let newer_path = home
+        .join("sessions/2025/01/02")
+        .join(format!("rollout-2025-01-02T12-00-00-{newer_id}.jsonl"));
+
+    let user_message = RolloutLine {
+        timestamp: "2025-01-02T12-00-01".to_string(),
+        item: RolloutItem::ResponseItem(ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: "fix the flaky test\nit fails on CI".to_string(),
+            }],
+        }),
+    };
+    let mut file = OpenOptions::new().append(true).open(&newer_path).unwrap();
+    writeln!(file, "{}", serde_json::to_string(&user_message).unwrap()).unwrap();
+
+    let summaries = list_rollout_summaries(home, 10, NO_SOURCE_FILTER)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        vec![
+            RolloutSummary {
+                path: newer_path,
+                conversation_id: ConversationId::from_string(&newer_id.to_string()).unwrap(),
+                created_at: Some("2025-01-02T12-00-00".to_string()),
+                preview: Some("fix the flaky test".to_string()),
+            },
+            RolloutSummary {
+                path: older_path,
+                conversation_id: ConversationId::from_string(&older_id.to_string()).unwrap(),
+                created_at: Some("2025-01-01T12-00-00".to_string()),
+                preview: None,
+            },
+        ],
+        summaries
+    );
+}
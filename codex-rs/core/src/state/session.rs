@@ -7,6 +7,15 @@ use crate::conversation_history::ConversationHistory;
 use crate::protocol::RateLimitSnapshot;
 use crate::protocol::TokenUsage;
 use crate::protocol::TokenUsageInfo;
+use crate::token_estimate::estimate_tokens;
+
+/// Running totals for the full-history compactions completed in a session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct CompactionStats {
+    pub(crate) count: u64,
+    pub(crate) total_items_dropped: u64,
+    pub(crate) total_tokens_saved: u64,
+}
 
 /// Persistent, session-scoped state previously stored directly on `Session`.
 pub(crate) struct SessionState {
@@ -14,6 +23,7 @@ pub(crate) struct SessionState {
     pub(crate) history: ConversationHistory,
     pub(crate) token_info: Option<TokenUsageInfo>,
     pub(crate) latest_rate_limits: Option<RateLimitSnapshot>,
+    pub(crate) compaction_stats: CompactionStats,
 }
 
 impl SessionState {
@@ -24,6 +34,7 @@ impl SessionState {
             history: ConversationHistory::new(),
             token_info: None,
             latest_rate_limits: None,
+            compaction_stats: CompactionStats::default(),
         }
     }
 
@@ -44,10 +55,24 @@ impl SessionState {
         self.history.clone()
     }
 
+    /// Replace the history without touching the compaction totals, for edits
+    /// that rewrite individual items rather than compacting the conversation.
     pub(crate) fn replace_history(&mut self, items: Vec<ResponseItem>) {
         self.history.replace(items);
     }
 
+    /// Replace the history with its compacted form and fold the difference
+    /// into the session's compaction totals.
+    pub(crate) fn replace_history_after_compaction(&mut self, items: Vec<ResponseItem>) {
+        let before = self.history.get_history();
+        let items_dropped = before.len().saturating_sub(items.len());
+        let tokens_saved = estimate_tokens(&before).saturating_sub(estimate_tokens(&items));
+        self.compaction_stats.count += 1;
+        self.compaction_stats.total_items_dropped += items_dropped as u64;
+        self.compaction_stats.total_tokens_saved += tokens_saved as u64;
+        self.history.replace(items);
+    }
+
     // Token/rate limit helpers
     pub(crate) fn update_token_info_from_usage(
         &mut self,
@@ -30,3 +30,49 @@ impl SessionTask for CompactTask {
         compact::run_compact_task(session.clone_session(), ctx, input).await
     }
 }
+
+/// Summarizes oversized tool outputs in place instead of compacting the whole
+/// conversation.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ToolOutputCompactTask;
+
+#[async_trait]
+impl SessionTask for ToolOutputCompactTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Compact
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        session: Arc<SessionTaskContext>,
+        ctx: Arc<TurnContext>,
+        _input: Vec<UserInput>,
+        _cancellation_token: CancellationToken,
+    ) -> Option<String> {
+        compact::run_tool_output_compact_task(session.clone_session(), ctx).await
+    }
+}
+
+/// Summarizes history items `[start, end)` in place; see `Op::CompactRange`.
+#[derive(Clone, Copy)]
+pub(crate) struct RangeCompactTask {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+#[async_trait]
+impl SessionTask for RangeCompactTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Compact
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        session: Arc<SessionTaskContext>,
+        ctx: Arc<TurnContext>,
+        _input: Vec<UserInput>,
+        _cancellation_token: CancellationToken,
+    ) -> Option<String> {
+        compact::run_range_compact_task(session.clone_session(), ctx, self.start, self.end).await
+    }
+}
@@ -25,6 +25,8 @@ use crate::state::TaskKind;
 use codex_protocol::user_input::UserInput;
 
 pub(crate) use compact::CompactTask;
+pub(crate) use compact::RangeCompactTask;
+pub(crate) use compact::ToolOutputCompactTask;
 pub(crate) use regular::RegularTask;
 pub(crate) use review::ReviewTask;
 
@@ -0,0 +1,141 @@
+//! Cheap, tokenizer-free estimates of how many tokens history items occupy.
+//!
+//! These are heuristics (approx. 4 bytes/token) intended for budgeting
+//! decisions such as compaction thresholds; they are not exact counts.
+
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ReasoningItemReasoningSummary;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::models::WebSearchAction;
+
+/// Approximate number of bytes per token for English text and code.
+const APPROX_BYTES_PER_TOKEN: usize = 4;
+
+/// Fixed cost charged for every item to account for role markers, item
+/// framing, and call ids that the serialized request carries.
+pub const PER_ITEM_TOKEN_OVERHEAD: usize = 4;
+
+/// Estimate the number of tokens in a plain string.
+pub fn estimate_text_tokens(text: &str) -> usize {
+    text.len().div_ceil(APPROX_BYTES_PER_TOKEN)
+}
+
+/// Estimate the number of tokens the given items occupy in a prompt.
+pub fn estimate_tokens(items: &[ResponseItem]) -> usize {
+    items.iter().map(estimate_item_tokens).sum()
+}
+
+fn estimate_item_tokens(item: &ResponseItem) -> usize {
+    let content_bytes = match item {
+        ResponseItem::Message { content, .. } => content
+            .iter()
+            .map(|content_item| match content_item {
+                ContentItem::InputText { text } | ContentItem::OutputText { text } => text.len(),
+                ContentItem::InputImage { .. } => 0,
+            })
+            .sum(),
+        ResponseItem::Reasoning {
+            summary, content, ..
+        } => {
+            let summary_bytes: usize = summary
+                .iter()
+                .map(|entry| match entry {
+                    ReasoningItemReasoningSummary::SummaryText { text } => text.len(),
+                })
+                .sum();
+            let content_bytes: usize = content
+                .iter()
+                .flatten()
+                .map(|entry| match entry {
+                    ReasoningItemContent::ReasoningText { text }
+                    | ReasoningItemContent::Text { text } => text.len(),
+                })
+                .sum();
+            summary_bytes + content_bytes
+        }
+        ResponseItem::LocalShellCall { action, .. } => match action {
+            LocalShellAction::Exec(exec) => exec.command.iter().map(String::len).sum(),
+        },
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => name.len() + arguments.len(),
+        ResponseItem::FunctionCallOutput { output, .. } => output.content.len(),
+        ResponseItem::CustomToolCall { name, input, .. } => name.len() + input.len(),
+        ResponseItem::CustomToolCallOutput { output, .. } => output.len(),
+        ResponseItem::WebSearchCall { action, .. } => match action {
+            WebSearchAction::Search { query } => query.len(),
+            WebSearchAction::Other => 0,
+        },
+        ResponseItem::Other => 0,
+    };
+    PER_ITEM_TOKEN_OVERHEAD + content_bytes.div_ceil(APPROX_BYTES_PER_TOKEN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn user_msg(text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn empty_items_contribute_only_overhead() {
+        let items = vec![
+            user_msg(""),
+            ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload {
+                    content: String::new(),
+                    success: None,
+                },
+            },
+            ResponseItem::Other,
+        ];
+
+        assert_eq!(3 * PER_ITEM_TOKEN_OVERHEAD, estimate_tokens(&items));
+        assert_eq!(0, estimate_tokens(&[]));
+    }
+
+    #[test]
+    fn estimate_grows_monotonically_with_content() {
+        let mut previous = estimate_tokens(&[user_msg("")]);
+        for len in [1, 4, 5, 64, 1_000, 10_000] {
+            let current = estimate_tokens(&[user_msg(&"x".repeat(len))]);
+            assert!(
+                current >= previous,
+                "estimate shrank from {previous} to {current} at {len} bytes"
+            );
+            previous = current;
+        }
+    }
+
+    #[test]
+    fn counts_tool_arguments_and_outputs() {
+        let items = vec![
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: "x".repeat(35),
+                call_id: "call-1".to_string(),
+            },
+            ResponseItem::CustomToolCallOutput {
+                call_id: "call-2".to_string(),
+                output: "y".repeat(40),
+            },
+        ];
+
+        // "shell" (5 bytes) + 35 bytes of arguments = 10 tokens; 40 bytes of output = 10 tokens.
+        assert_eq!(2 * PER_ITEM_TOKEN_OVERHEAD + 20, estimate_tokens(&items));
+    }
+}
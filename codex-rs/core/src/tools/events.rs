@@ -110,7 +110,7 @@ impl ToolEmitter {
             (Self::Shell { command, cwd }, ToolEventStage::Begin) => {
                 emit_exec_command_begin(ctx, command, cwd.as_path()).await;
             }
-            (Self::Shell { .. }, ToolEventStage::Success(output)) => {
+            (Self::Shell { command, .. }, ToolEventStage::Success(output)) => {
                 emit_exec_end(
                     ctx,
                     output.stdout.text.clone(),
@@ -118,11 +118,14 @@ impl ToolEmitter {
                     output.aggregated_output.text.clone(),
                     output.exit_code,
                     output.duration,
-                    format_exec_output_str(&output),
+                    format_exec_output_str(&output, command),
                 )
                 .await;
             }
-            (Self::Shell { .. }, ToolEventStage::Failure(ToolEventFailure::Output(output))) => {
+            (
+                Self::Shell { command, .. },
+                ToolEventStage::Failure(ToolEventFailure::Output(output)),
+            ) => {
                 emit_exec_end(
                     ctx,
                     output.stdout.text.clone(),
@@ -130,7 +133,7 @@ impl ToolEmitter {
                     output.aggregated_output.text.clone(),
                     output.exit_code,
                     output.duration,
-                    format_exec_output_str(&output),
+                    format_exec_output_str(&output, command),
                 )
                 .await;
             }
@@ -77,6 +77,7 @@ impl ToolHandler for ApplyPatchHandler {
         let content = handle_container_exec_with_params(
             tool_name.as_str(),
             exec_params,
+            BTreeMap::new(),
             Arc::clone(&session),
             Arc::clone(&turn),
             Arc::clone(&tracker),
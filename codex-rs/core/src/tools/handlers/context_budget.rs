@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use codex_protocol::protocol::TokenUsageInfo;
+use serde::Serialize;
+
+use crate::client_common::tools::ResponsesApiTool;
+use crate::client_common::tools::ToolSpec;
+use crate::function_tool::FunctionCallError;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolOutput;
+use crate::tools::context::ToolPayload;
+use crate::tools::registry::ToolHandler;
+use crate::tools::registry::ToolKind;
+use crate::tools::spec::JsonSchema;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+pub struct ContextBudgetHandler;
+
+pub static CONTEXT_BUDGET_TOOL: LazyLock<ToolSpec> = LazyLock::new(|| {
+    ToolSpec::Function(ResponsesApiTool {
+        name: "context_budget".to_string(),
+        description: r#"Reports how much of the model context window this conversation is using.
+Returns used_tokens, window, percent_left, and auto_compact_at (the token count at which the conversation is compacted automatically). window, percent_left, and auto_compact_at are null when unknown.
+"#
+        .to_string(),
+        strict: false,
+        parameters: JsonSchema::Object {
+            properties: BTreeMap::new(),
+            required: None,
+            additional_properties: Some(false.into()),
+        },
+    })
+});
+
+#[derive(Debug, PartialEq, Serialize)]
+struct ContextBudget {
+    used_tokens: i64,
+    window: Option<i64>,
+    percent_left: Option<i64>,
+    auto_compact_at: Option<i64>,
+}
+
+#[async_trait]
+impl ToolHandler for ContextBudgetHandler {
+    fn kind(&self) -> ToolKind {
+        ToolKind::Function
+    }
+
+    async fn handle(&self, invocation: ToolInvocation) -> Result<ToolOutput, FunctionCallError> {
+        let ToolInvocation {
+            session,
+            turn,
+            payload,
+            ..
+        } = invocation;
+
+        if !matches!(payload, ToolPayload::Function { .. }) {
+            return Err(FunctionCallError::RespondToModel(
+                "context_budget handler received unsupported payload".to_string(),
+            ));
+        }
+
+        let info = session.token_usage_info().await;
+        let budget = context_budget(
+            info.as_ref(),
+            turn.client.get_model_context_window(),
+            turn.client.get_auto_compact_token_limit(),
+        );
+        let content = serde_json::to_string(&budget).map_err(|e| {
+            FunctionCallError::RespondToModel(format!("failed to serialize context budget: {e}"))
+        })?;
+
+        Ok(ToolOutput::Function {
+            content,
+            success: Some(true),
+        })
+    }
+}
+
+/// Combine the session's token accounting with the model's limits. The window
+/// recorded alongside the usage wins over `fallback_window`.
+fn context_budget(
+    info: Option<&TokenUsageInfo>,
+    fallback_window: Option<i64>,
+    auto_compact_at: Option<i64>,
+) -> ContextBudget {
+    let window = info
+        .and_then(|info| info.model_context_window)
+        .or(fallback_window);
+    let used_tokens = info.map_or(0, |info| info.total_token_usage.tokens_in_context_window());
+    let percent_left = window.map(|window| match info {
+        Some(info) => info
+            .total_token_usage
+            .percent_of_context_window_remaining(window),
+        None => 100,
+    });
+    ContextBudget {
+        used_tokens,
+        window,
+        percent_left,
+        auto_compact_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::protocol::TokenUsage;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn reports_percent_left_for_known_window() {
+        let info = TokenUsageInfo {
+            total_token_usage: TokenUsage {
+                input_tokens: 60_000,
+                output_tokens: 2_000,
+                reasoning_output_tokens: 0,
+                total_tokens: 62_000,
+                ..TokenUsage::default()
+            },
+            last_token_usage: TokenUsage::default(),
+            model_context_window: Some(112_000),
+        };
+
+        let budget = context_budget(Some(&info), None, Some(100_000));
+
+        // 50k of the 100k window above the 12k baseline is used.
+        assert_eq!(
+            ContextBudget {
+                used_tokens: 62_000,
+                window: Some(112_000),
+                percent_left: Some(50),
+                auto_compact_at: Some(100_000),
+            },
+            budget
+        );
+    }
+
+    #[test]
+    fn unknown_window_and_no_usage() {
+        assert_eq!(
+            ContextBudget {
+                used_tokens: 0,
+                window: None,
+                percent_left: None,
+                auto_compact_at: None,
+            },
+            context_budget(None, None, None)
+        );
+        assert_eq!(
+            ContextBudget {
+                used_tokens: 0,
+                window: Some(200_000),
+                percent_left: Some(100),
+                auto_compact_at: None,
+            },
+            context_budget(None, Some(200_000), None)
+        );
+    }
+}
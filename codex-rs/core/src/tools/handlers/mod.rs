@@ -1,4 +1,5 @@
 pub mod apply_patch;
+mod context_budget;
 mod grep_files;
 mod list_dir;
 mod mcp;
@@ -10,9 +11,11 @@ mod test_sync;
 mod unified_exec;
 mod view_image;
 
+pub use context_budget::CONTEXT_BUDGET_TOOL;
 pub use plan::PLAN_TOOL;
 
 pub use apply_patch::ApplyPatchHandler;
+pub use context_budget::ContextBudgetHandler;
 pub use grep_files::GrepFilesHandler;
 pub use list_dir::ListDirHandler;
 pub use mcp::McpHandler;
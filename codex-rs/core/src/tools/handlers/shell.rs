@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use codex_protocol::models::ShellToolCallParams;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::codex::TurnContext;
@@ -16,12 +17,24 @@ use crate::tools::registry::ToolKind;
 pub struct ShellHandler;
 
 impl ShellHandler {
+    /// Variables the model asked to set for this command only.
+    fn env_overlay(params: &ShellToolCallParams) -> BTreeMap<String, String> {
+        params
+            .env
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
     fn to_exec_params(params: ShellToolCallParams, turn_context: &TurnContext) -> ExecParams {
+        let mut env = create_env(&turn_context.shell_environment_policy);
+        env.extend(params.env.unwrap_or_default());
         ExecParams {
             command: params.command,
             cwd: turn_context.resolve_path(params.workdir.clone()),
             timeout_ms: params.timeout_ms,
-            env: create_env(&turn_context.shell_environment_policy),
+            env,
             with_escalated_permissions: params.with_escalated_permissions,
             justification: params.justification,
             arg0: None,
@@ -60,10 +73,12 @@ impl ToolHandler for ShellHandler {
                             "failed to parse function arguments: {e:?}"
                         ))
                     })?;
+                let env_overlay = Self::env_overlay(&params);
                 let exec_params = Self::to_exec_params(params, turn.as_ref());
                 let content = handle_container_exec_with_params(
                     tool_name.as_str(),
                     exec_params,
+                    env_overlay,
                     Arc::clone(&session),
                     Arc::clone(&turn),
                     Arc::clone(&tracker),
@@ -76,10 +91,12 @@ impl ToolHandler for ShellHandler {
                 })
             }
             ToolPayload::LocalShell { params } => {
+                let env_overlay = Self::env_overlay(&params);
                 let exec_params = Self::to_exec_params(params, turn.as_ref());
                 let content = handle_container_exec_with_params(
                     tool_name.as_str(),
                     exec_params,
+                    env_overlay,
                     Arc::clone(&session),
                     Arc::clone(&turn),
                     Arc::clone(&tracker),
@@ -37,7 +37,9 @@ use codex_protocol::protocol::AskForApproval;
 use codex_utils_string::take_bytes_at_char_boundary;
 use codex_utils_string::take_last_bytes_at_char_boundary;
 pub use router::ToolRouter;
+use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use tracing::trace;
 
@@ -54,10 +56,26 @@ pub(crate) const TELEMETRY_PREVIEW_MAX_LINES: usize = 64; // lines
 pub(crate) const TELEMETRY_PREVIEW_TRUNCATION_NOTICE: &str =
     "[... telemetry preview truncated ...]";
 
+/// Longest command echoed back in a timeout message.
+const TIMEOUT_COMMAND_MAX_BYTES: usize = 200;
+
+/// How exec stdout and stderr are presented to the model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExecOutputFormat {
+    /// The aggregated output, interleaved as the command produced it.
+    Combined,
+    /// Separate `stdout:` and `stderr:` sections.
+    Labeled,
+}
+
 // TODO(jif) break this down
+/// `env_overlay` holds the variables the model asked to set on top of the
+/// policy environment; they are already merged into `params.env` and are
+/// passed separately so approval can account for them.
 pub(crate) async fn handle_container_exec_with_params(
     tool_name: &str,
     params: ExecParams,
+    env_overlay: BTreeMap<String, String>,
     sess: Arc<Session>,
     turn_context: Arc<TurnContext>,
     turn_diff_tracker: SharedTurnDiffTracker,
@@ -149,7 +167,7 @@ pub(crate) async fn handle_container_exec_with_params(
             )
             .await;
 
-        handle_exec_outcome(&event_emitter, event_ctx, out).await
+        handle_exec_outcome(&event_emitter, event_ctx, out, &params.command).await
     } else {
         // Route shell execution through the new orchestrator/runtime.
         let req = ShellRequest {
@@ -157,6 +175,7 @@ pub(crate) async fn handle_container_exec_with_params(
             cwd: params.cwd.clone(),
             timeout_ms: params.timeout_ms,
             env: params.env.clone(),
+            env_overlay,
             with_escalated_permissions: params.with_escalated_permissions,
             justification: params.justification.clone(),
         };
@@ -180,7 +199,7 @@ pub(crate) async fn handle_container_exec_with_params(
             )
             .await;
 
-        handle_exec_outcome(&event_emitter, event_ctx, out).await
+        handle_exec_outcome(&event_emitter, event_ctx, out, &params.command).await
     }
 }
 
@@ -188,11 +207,13 @@ async fn handle_exec_outcome(
     event_emitter: &ToolEmitter,
     event_ctx: ToolEventCtx<'_>,
     out: Result<ExecToolCallOutput, ToolError>,
+    command: &[String],
 ) -> Result<String, FunctionCallError> {
     let event;
+    let format = event_ctx.turn.tools_config.exec_output_format;
     let result = match out {
         Ok(output) => {
-            let content = format_exec_output_for_model(&output);
+            let content = format_exec_output_for_model(&output, format, command);
             let exit_code = output.exit_code;
             event = ToolEventStage::Success(output);
             if exit_code == 0 {
@@ -203,7 +224,7 @@ async fn handle_exec_outcome(
         }
         Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Timeout { output })))
         | Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied { output }))) => {
-            let response = format_exec_output_for_model(&output);
+            let response = format_exec_output_for_model(&output, format, command);
             event = ToolEventStage::Failure(ToolEventFailure::Output(*output));
             Err(FunctionCallError::RespondToModel(response))
         }
@@ -234,34 +255,42 @@ async fn handle_exec_outcome(
     result
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ExecMetadata {
+    pub(crate) exit_code: i32,
+    pub(crate) duration_seconds: f32,
+}
+
+/// The JSON payload an exec tool call returns to the model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ExecOutput {
+    pub(crate) output: String,
+    pub(crate) metadata: ExecMetadata,
+}
+
 /// Format the combined exec output for sending back to the model.
 /// Includes exit code and duration metadata; truncates large bodies safely.
-pub fn format_exec_output_for_model(exec_output: &ExecToolCallOutput) -> String {
+pub(crate) fn format_exec_output_for_model(
+    exec_output: &ExecToolCallOutput,
+    format: ExecOutputFormat,
+    command: &[String],
+) -> String {
     let ExecToolCallOutput {
         exit_code,
         duration,
         ..
     } = exec_output;
 
-    #[derive(Serialize)]
-    struct ExecMetadata {
-        exit_code: i32,
-        duration_seconds: f32,
-    }
-
-    #[derive(Serialize)]
-    struct ExecOutput<'a> {
-        output: &'a str,
-        metadata: ExecMetadata,
-    }
-
     // round to 1 decimal place
     let duration_seconds = ((duration.as_secs_f32()) * 10.0).round() / 10.0;
 
-    let formatted_output = format_exec_output_str(exec_output);
+    let formatted_output = match format {
+        ExecOutputFormat::Combined => format_exec_output_str(exec_output, command),
+        ExecOutputFormat::Labeled => format_labeled_exec_output_str(exec_output, command),
+    };
 
     let payload = ExecOutput {
-        output: &formatted_output,
+        output: formatted_output,
         metadata: ExecMetadata {
             exit_code: *exit_code,
             duration_seconds,
@@ -272,7 +301,13 @@ pub fn format_exec_output_for_model(exec_output: &ExecToolCallOutput) -> String
     serde_json::to_string(&payload).expect("serialize ExecOutput")
 }
 
-pub fn format_exec_output_str(exec_output: &ExecToolCallOutput) -> String {
+/// Read back a payload produced by [`format_exec_output_for_model`], or `None`
+/// when `content` came from some other tool.
+pub(crate) fn parse_exec_output_for_model(content: &str) -> Option<ExecOutput> {
+    serde_json::from_str(content).ok()
+}
+
+pub fn format_exec_output_str(exec_output: &ExecToolCallOutput, command: &[String]) -> String {
     let ExecToolCallOutput {
         aggregated_output, ..
     } = exec_output;
@@ -280,16 +315,54 @@ pub fn format_exec_output_str(exec_output: &ExecToolCallOutput) -> String {
     let content = aggregated_output.text.as_str();
 
     if exec_output.timed_out {
-        let prefixed = format!(
-            "command timed out after {} milliseconds\n{content}",
-            exec_output.duration.as_millis()
-        );
+        let prefixed = format!("{}\n{content}", timed_out_message(exec_output, command));
         return format_exec_output(&prefixed);
     }
 
     format_exec_output(content)
 }
 
+/// Like [`format_exec_output_str`], but with stdout and stderr in separately
+/// labeled sections. Truncation applies to the combined text so the budgets
+/// match the unlabeled format.
+fn format_labeled_exec_output_str(exec_output: &ExecToolCallOutput, command: &[String]) -> String {
+    let ExecToolCallOutput { stdout, stderr, .. } = exec_output;
+
+    let mut content = String::new();
+    for (label, stream) in [("stdout", &stdout.text), ("stderr", &stderr.text)] {
+        if stream.is_empty() {
+            continue;
+        }
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&format!("{label}:\n{stream}"));
+    }
+
+    if exec_output.timed_out {
+        content = format!("{}\n{content}", timed_out_message(exec_output, command));
+    }
+
+    format_exec_output(&content)
+}
+
+/// First line of the output of a command that hit its timeout, naming the
+/// command (shortened to `TIMEOUT_COMMAND_MAX_BYTES`).
+fn timed_out_message(exec_output: &ExecToolCallOutput, command: &[String]) -> String {
+    let command =
+        shlex::try_join(command.iter().map(String::as_str)).unwrap_or_else(|_| command.join(" "));
+    let shown = take_bytes_at_char_boundary(&command, TIMEOUT_COMMAND_MAX_BYTES);
+    let ellipsis = if shown.len() < command.len() {
+        "…"
+    } else {
+        ""
+    };
+    format!(
+        "command timed out after {} milliseconds: {shown}{ellipsis}",
+        exec_output.duration.as_millis()
+    )
+}
+
 pub(super) fn format_exec_output(content: &str) -> String {
     // Head+tail truncation for the model: show the beginning and end with an elision.
     // Clients still receive full streams; only this formatted summary is capped.
@@ -363,6 +436,8 @@ fn truncate_formatted_exec_output(content: &str, total_lines: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exec::StreamOutput;
+    use pretty_assertions::assert_eq;
     use regex_lite::Regex;
 
     fn truncate_function_error(err: FunctionCallError) -> FunctionCallError {
@@ -523,4 +598,45 @@ mod tests {
             "line omission marker should take precedence over byte marker: {truncated}"
         );
     }
+
+    fn exec_output(stdout: &str, stderr: &str) -> ExecToolCallOutput {
+        ExecToolCallOutput {
+            exit_code: 1,
+            stdout: StreamOutput::new(stdout.to_string()),
+            stderr: StreamOutput::new(stderr.to_string()),
+            aggregated_output: StreamOutput::new(format!("{stdout}{stderr}")),
+            duration: std::time::Duration::from_secs(1),
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn labeled_exec_output_separates_streams() {
+        let output = exec_output("compiling crate\n", "error: missing semicolon\n");
+
+        assert_eq!(
+            "stdout:\ncompiling crate\nstderr:\nerror: missing semicolon\n",
+            format_labeled_exec_output_str(&output, &[])
+        );
+        assert_eq!(
+            "compiling crate\nerror: missing semicolon\n",
+            format_exec_output_str(&output, &[])
+        );
+    }
+
+    #[test]
+    fn labeled_exec_output_truncates_across_both_streams() {
+        let line = "stdout line that pushes the output past the limits\n";
+        let stdout = line.repeat(2_000);
+        let output = exec_output(&stdout, "error: final failure\n");
+        let total_lines = 2_000 + 3;
+
+        let formatted = format_labeled_exec_output_str(&output, &[]);
+
+        assert_truncated_message_matches(&formatted, "stdout:\n", total_lines);
+        assert!(
+            formatted.ends_with("stderr:\nerror: final failure\n"),
+            "expected stderr section in the retained tail: {formatted}"
+        );
+    }
 }
@@ -116,6 +116,7 @@ impl ToolRouter {
                             timeout_ms: exec.timeout_ms,
                             with_escalated_permissions: None,
                             justification: None,
+                            env: exec.env,
                         };
                         Ok(Some(ToolCall {
                             tool_name: "local_shell".to_string(),
@@ -144,6 +145,19 @@ impl ToolRouter {
         let payload_outputs_custom = matches!(payload, ToolPayload::Custom { .. });
         let failure_call_id = call_id.clone();
 
+        let arg_bytes = payload.log_payload().len();
+        if arg_bytes > turn.max_tool_arg_bytes {
+            let message = format!(
+                "arguments for {tool_name} are {arg_bytes} bytes, which exceeds the limit of {} bytes; shorten the arguments (for example, split large content across several smaller calls) and try again",
+                turn.max_tool_arg_bytes
+            );
+            return Ok(Self::failure_response(
+                failure_call_id,
+                payload_outputs_custom,
+                FunctionCallError::RespondToModel(message),
+            ));
+        }
+
         let invocation = ToolInvocation {
             session,
             turn,
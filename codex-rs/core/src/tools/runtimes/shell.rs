@@ -22,6 +22,7 @@ use crate::tools::sandboxing::with_cached_approval;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::ReviewDecision;
 use futures::future::BoxFuture;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(Clone, Debug)]
@@ -30,6 +31,10 @@ pub struct ShellRequest {
     pub cwd: PathBuf,
     pub timeout_ms: Option<u64>,
     pub env: std::collections::HashMap<String, String>,
+    /// Variables the model set for this command on top of the policy
+    /// environment. They can change what the command runs, so they are part
+    /// of the approval.
+    pub env_overlay: BTreeMap<String, String>,
     pub with_escalated_permissions: Option<bool>,
     pub justification: Option<String>,
 }
@@ -41,6 +46,7 @@ pub struct ShellRuntime;
 pub(crate) struct ApprovalKey {
     command: Vec<String>,
     cwd: PathBuf,
+    env_overlay: BTreeMap<String, String>,
     escalated: bool,
 }
 
@@ -74,6 +80,7 @@ impl Approvable<ShellRequest> for ShellRuntime {
         ApprovalKey {
             command: req.command.clone(),
             cwd: req.cwd.clone(),
+            env_overlay: req.env_overlay.clone(),
             escalated: req.with_escalated_permissions.unwrap_or(false),
         }
     }
@@ -86,10 +93,12 @@ impl Approvable<ShellRequest> for ShellRuntime {
         let key = self.approval_key(req);
         let command = req.command.clone();
         let cwd = req.cwd.clone();
-        let reason = ctx
-            .retry_reason
-            .clone()
-            .or_else(|| req.justification.clone());
+        let reason = approval_reason(
+            ctx.retry_reason
+                .clone()
+                .or_else(|| req.justification.clone()),
+            &req.env_overlay,
+        );
         let session = ctx.session;
         let turn = ctx.turn;
         let call_id = ctx.call_id.to_string();
@@ -109,7 +118,8 @@ impl Approvable<ShellRequest> for ShellRuntime {
         policy: AskForApproval,
         sandbox_policy: &SandboxPolicy,
     ) -> bool {
-        if is_known_safe_command(&req.command) {
+        let known_safe = is_known_safe_command_with_env(req);
+        if known_safe {
             return false;
         }
         match policy {
@@ -129,7 +139,7 @@ impl Approvable<ShellRequest> for ShellRuntime {
                 }
                 command_might_be_dangerous(&req.command)
             }
-            AskForApproval::UnlessTrusted => !is_known_safe_command(&req.command),
+            AskForApproval::UnlessTrusted => !known_safe,
         }
     }
 
@@ -138,6 +148,36 @@ impl Approvable<ShellRequest> for ShellRuntime {
     }
 }
 
+/// A command is only known-safe when it runs in the policy environment: an
+/// overlay such as `LD_PRELOAD` or `PATH` can make `ls` run arbitrary code.
+fn is_known_safe_command_with_env(req: &ShellRequest) -> bool {
+    req.env_overlay.is_empty() && is_known_safe_command(&req.command)
+}
+
+/// Append the overlay to the approval reason so the user sees every variable
+/// the command sets before approving it.
+fn approval_reason(
+    reason: Option<String>,
+    env_overlay: &BTreeMap<String, String>,
+) -> Option<String> {
+    if env_overlay.is_empty() {
+        return reason;
+    }
+    let assignments = env_overlay
+        .iter()
+        .map(|(key, value)| {
+            let value = shlex::try_quote(value).unwrap_or_else(|_| format!("{value:?}").into());
+            format!("{key}={value}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let env_note = format!("Sets environment variables: {assignments}");
+    Some(match reason {
+        Some(reason) => format!("{reason}\n{env_note}"),
+        None => env_note,
+    })
+}
+
 impl ToolRuntime<ShellRequest, ExecToolCallOutput> for ShellRuntime {
     async fn run(
         &mut self,
@@ -162,3 +202,56 @@ impl ToolRuntime<ShellRequest, ExecToolCallOutput> for ShellRuntime {
         Ok(out)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn request(env_overlay: BTreeMap<String, String>) -> ShellRequest {
+        ShellRequest {
+            command: vec!["ls".to_string()],
+            cwd: PathBuf::from("/repo"),
+            timeout_ms: None,
+            env: std::collections::HashMap::new(),
+            env_overlay,
+            with_escalated_permissions: None,
+            justification: None,
+        }
+    }
+
+    #[test]
+    fn env_overlay_makes_safe_command_need_approval() {
+        let runtime = ShellRuntime::new();
+        let plain = request(BTreeMap::new());
+        let preloaded = request(BTreeMap::from([(
+            "LD_PRELOAD".to_string(),
+            "/tmp/evil.so".to_string(),
+        )]));
+        let policy = SandboxPolicy::new_read_only_policy();
+
+        assert!(!runtime.wants_initial_approval(&plain, AskForApproval::UnlessTrusted, &policy));
+        assert!(runtime.wants_initial_approval(&preloaded, AskForApproval::UnlessTrusted, &policy));
+        assert_ne!(
+            runtime.approval_key(&plain),
+            runtime.approval_key(&preloaded)
+        );
+    }
+
+    #[test]
+    fn approval_reason_lists_env_overlay() {
+        let env_overlay = BTreeMap::from([
+            ("PATH".to_string(), "/tmp/bin".to_string()),
+            ("RUSTC_WRAPPER".to_string(), "sccache".to_string()),
+        ]);
+
+        assert_eq!(
+            Some(
+                "run the tests\nSets environment variables: PATH=/tmp/bin RUSTC_WRAPPER=sccache"
+                    .to_string()
+            ),
+            approval_reason(Some("run the tests".to_string()), &env_overlay)
+        );
+        assert_eq!(None, approval_reason(None, &BTreeMap::new()));
+    }
+}
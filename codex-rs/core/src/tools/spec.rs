@@ -3,6 +3,8 @@ use crate::client_common::tools::ToolSpec;
 use crate::features::Feature;
 use crate::features::Features;
 use crate::model_family::ModelFamily;
+use crate::tools::ExecOutputFormat;
+use crate::tools::handlers::CONTEXT_BUDGET_TOOL;
 use crate::tools::handlers::PLAN_TOOL;
 use crate::tools::handlers::apply_patch::ApplyPatchToolType;
 use crate::tools::handlers::apply_patch::create_apply_patch_freeform_tool;
@@ -28,6 +30,8 @@ pub(crate) struct ToolsConfig {
     pub apply_patch_tool_type: Option<ApplyPatchToolType>,
     pub web_search_request: bool,
     pub include_view_image_tool: bool,
+    pub include_context_budget_tool: bool,
+    pub exec_output_format: ExecOutputFormat,
     pub experimental_unified_exec_tool: bool,
     pub experimental_supported_tools: Vec<String>,
 }
@@ -48,6 +52,12 @@ impl ToolsConfig {
         let include_apply_patch_tool = features.enabled(Feature::ApplyPatchFreeform);
         let include_web_search_request = features.enabled(Feature::WebSearchRequest);
         let include_view_image_tool = features.enabled(Feature::ViewImageTool);
+        let include_context_budget_tool = features.enabled(Feature::ContextBudgetTool);
+        let exec_output_format = if features.enabled(Feature::LabeledExecOutput) {
+            ExecOutputFormat::Labeled
+        } else {
+            ExecOutputFormat::Combined
+        };
 
         let shell_type = if use_streamable_shell_tool {
             ConfigShellToolType::Streamable
@@ -74,6 +84,8 @@ impl ToolsConfig {
             apply_patch_tool_type,
             web_search_request: include_web_search_request,
             include_view_image_tool,
+            include_context_budget_tool,
+            exec_output_format,
             experimental_unified_exec_tool,
             experimental_supported_tools: model_family.experimental_supported_tools.clone(),
         }
@@ -268,6 +280,14 @@ fn create_shell_tool() -> ToolSpec {
             description: Some("Only set if with_escalated_permissions is true. 1-sentence explanation of why we want to run this command.".to_string()),
         },
     );
+    properties.insert(
+        "env".to_string(),
+        JsonSchema::Object {
+            properties: BTreeMap::new(),
+            required: None,
+            additional_properties: Some(JsonSchema::String { description: None }.into()),
+        },
+    );
 
     ToolSpec::Function(ResponsesApiTool {
         name: "shell".to_string(),
@@ -864,6 +884,7 @@ pub(crate) fn build_specs(
     mcp_tools: Option<HashMap<String, mcp_types::Tool>>,
 ) -> ToolRegistryBuilder {
     use crate::tools::handlers::ApplyPatchHandler;
+    use crate::tools::handlers::ContextBudgetHandler;
     use crate::tools::handlers::GrepFilesHandler;
     use crate::tools::handlers::ListDirHandler;
     use crate::tools::handlers::McpHandler;
@@ -980,6 +1001,11 @@ pub(crate) fn build_specs(
         builder.register_handler("view_image", view_image_handler);
     }
 
+    if config.include_context_budget_tool {
+        builder.push_spec_with_parallel_support(CONTEXT_BUDGET_TOOL.clone(), true);
+        builder.register_handler("context_budget", Arc::new(ContextBudgetHandler));
+    }
+
     if let Some(mcp_tools) = mcp_tools {
         let mut entries: Vec<(String, mcp_types::Tool)> = mcp_tools.into_iter().collect();
         entries.sort_by(|a, b| a.0.cmp(&b.0));
@@ -1649,7 +1675,10 @@ mod tests {
     fn test_shell_tool() {
         let tool = super::create_shell_tool();
         let ToolSpec::Function(ResponsesApiTool {
-            description, name, ..
+            description,
+            name,
+            parameters,
+            ..
         }) = &tool
         else {
             panic!("expected function tool");
@@ -1658,6 +1687,24 @@ mod tests {
 
         let expected = "Runs a shell command and returns its output.";
         assert_eq!(description, expected);
+
+        let JsonSchema::Object {
+            properties,
+            required,
+            ..
+        } = parameters
+        else {
+            panic!("expected object parameters");
+        };
+        assert_eq!(
+            Some(&JsonSchema::Object {
+                properties: BTreeMap::new(),
+                required: None,
+                additional_properties: Some(JsonSchema::String { description: None }.into()),
+            }),
+            properties.get("env")
+        );
+        assert_eq!(&Some(vec!["command".to_string()]), required);
     }
 
     #[test]
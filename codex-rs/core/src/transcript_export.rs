@@ -0,0 +1,322 @@
+//! Render the in-memory conversation history as a readable transcript.
+//!
+//! Session prefix messages (user instructions, environment context) and the
+//! summaries that compaction splices into the history are skipped so the
+//! export only contains what the user and the model said and the tools that
+//! ran in between.
+
+use std::path::Path;
+
+use codex_protocol::items::TurnItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::models::WebSearchAction;
+use codex_protocol::protocol::TranscriptFormat;
+use serde::Serialize;
+
+use crate::codex::compact::content_items_to_text;
+use crate::codex::compact::is_compaction_summary_message;
+use crate::event_mapping::parse_turn_item;
+use crate::tools::parse_exec_output_for_model;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum TranscriptEntry {
+    User {
+        text: String,
+    },
+    Assistant {
+        text: String,
+    },
+    ToolCall {
+        name: String,
+        input: String,
+    },
+    ToolOutput {
+        output: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        exit_code: Option<i32>,
+    },
+}
+
+/// Render `items` in the requested format and write the result to `path`.
+pub(crate) async fn write_transcript(
+    items: &[ResponseItem],
+    format: TranscriptFormat,
+    path: &Path,
+) -> std::io::Result<()> {
+    let contents = render_transcript(items, format).map_err(std::io::Error::other)?;
+    tokio::fs::write(path, contents).await
+}
+
+pub(crate) fn render_transcript(
+    items: &[ResponseItem],
+    format: TranscriptFormat,
+) -> serde_json::Result<String> {
+    let entries = transcript_entries(items);
+    match format {
+        TranscriptFormat::Markdown => Ok(render_markdown(&entries)),
+        TranscriptFormat::Json => serde_json::to_string_pretty(&entries),
+    }
+}
+
+fn transcript_entries(items: &[ResponseItem]) -> Vec<TranscriptEntry> {
+    items.iter().filter_map(transcript_entry).collect()
+}
+
+fn transcript_entry(item: &ResponseItem) -> Option<TranscriptEntry> {
+    match item {
+        ResponseItem::Message { role, content, .. } if role == "assistant" => {
+            content_items_to_text(content).map(|text| TranscriptEntry::Assistant { text })
+        }
+        ResponseItem::Message { .. } if is_compaction_summary_message(item) => None,
+        ResponseItem::Message { .. } => match parse_turn_item(item)? {
+            TurnItem::UserMessage(user) => Some(TranscriptEntry::User {
+                text: user.message(),
+            }),
+            _ => None,
+        },
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => Some(TranscriptEntry::ToolCall {
+            name: name.clone(),
+            input: arguments.clone(),
+        }),
+        ResponseItem::CustomToolCall { name, input, .. } => Some(TranscriptEntry::ToolCall {
+            name: name.clone(),
+            input: input.clone(),
+        }),
+        ResponseItem::LocalShellCall { action, .. } => match action {
+            LocalShellAction::Exec(exec) => Some(TranscriptEntry::ToolCall {
+                name: "local_shell".to_string(),
+                input: exec.command.join(" "),
+            }),
+        },
+        ResponseItem::WebSearchCall { action, .. } => match action {
+            WebSearchAction::Search { query } => Some(TranscriptEntry::ToolCall {
+                name: "web_search".to_string(),
+                input: query.clone(),
+            }),
+            WebSearchAction::Other => None,
+        },
+        ResponseItem::FunctionCallOutput { output, .. } => Some(tool_output_entry(&output.content)),
+        ResponseItem::CustomToolCallOutput { output, .. } => Some(tool_output_entry(output)),
+        ResponseItem::Reasoning { .. } | ResponseItem::Other => None,
+    }
+}
+
+/// Exec tools return their output wrapped in a JSON payload for the model;
+/// unwrap it so the transcript shows the command output itself.
+fn tool_output_entry(content: &str) -> TranscriptEntry {
+    match parse_exec_output_for_model(content) {
+        Some(exec) => TranscriptEntry::ToolOutput {
+            output: exec.output,
+            exit_code: Some(exec.metadata.exit_code),
+        },
+        None => TranscriptEntry::ToolOutput {
+            output: content.to_string(),
+            exit_code: None,
+        },
+    }
+}
+
+fn render_markdown(entries: &[TranscriptEntry]) -> String {
+    let mut sections = vec!["# Conversation transcript".to_string()];
+    for entry in entries {
+        let section = match entry {
+            TranscriptEntry::User { text } => format!("## User\n\n{}", text.trim_end()),
+            TranscriptEntry::Assistant { text } => {
+                format!("## Assistant\n\n{}", text.trim_end())
+            }
+            TranscriptEntry::ToolCall { name, input } => {
+                format!("### Tool call: `{name}`\n\n{}", code_block(input))
+            }
+            TranscriptEntry::ToolOutput {
+                output,
+                exit_code: Some(exit_code),
+            } => format!(
+                "### Tool output (exit code {exit_code})\n\n{}",
+                code_block(output)
+            ),
+            TranscriptEntry::ToolOutput {
+                output,
+                exit_code: None,
+            } => format!("### Tool output\n\n{}", code_block(output)),
+        };
+        sections.push(section);
+    }
+    let mut markdown = sections.join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+/// Wrap `text` in a fenced code block whose fence is longer than any run of
+/// backticks inside `text`.
+fn code_block(text: &str) -> String {
+    let mut longest_run = 0;
+    let mut current_run = 0;
+    for ch in text.chars() {
+        if ch == '`' {
+            current_run += 1;
+            longest_run = longest_run.max(current_run);
+        } else {
+            current_run = 0;
+        }
+    }
+    let fence = "`".repeat((longest_run + 1).max(3));
+    format!("{fence}\n{}\n{fence}", text.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codex::compact::build_compacted_history;
+    use crate::codex::compact::splice_range_summary;
+    use crate::exec::ExecToolCallOutput;
+    use crate::exec::StreamOutput;
+    use crate::tools::ExecOutputFormat;
+    use crate::tools::format_exec_output_for_model;
+    use codex_protocol::models::ContentItem;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn message(role: &str, text: &str) -> ResponseItem {
+        let content = if role == "assistant" {
+            ContentItem::OutputText {
+                text: text.to_string(),
+            }
+        } else {
+            ContentItem::InputText {
+                text: text.to_string(),
+            }
+        };
+        ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![content],
+        }
+    }
+
+    #[test]
+    fn markdown_export_includes_turns_and_tool_calls() {
+        let items = vec![
+            message(
+                "user",
+                "<environment_context>\n  <cwd>/repo</cwd>\n</environment_context>",
+            ),
+            message("user", "list the files"),
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["ls"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload {
+                    content: "Cargo.toml\nsrc".to_string(),
+                    success: Some(true),
+                },
+            },
+            message("assistant", "There are two entries."),
+        ];
+
+        let markdown = render_transcript(&items, TranscriptFormat::Markdown).unwrap();
+
+        assert_eq!(
+            "# Conversation transcript\n\n\
+             ## User\n\nlist the files\n\n\
+             ### Tool call: `shell`\n\n```\n{\"command\":[\"ls\"]}\n```\n\n\
+             ### Tool output\n\n```\nCargo.toml\nsrc\n```\n\n\
+             ## Assistant\n\nThere are two entries.\n",
+            markdown
+        );
+    }
+
+    #[test]
+    fn markdown_export_unwraps_exec_output() {
+        let exec_output = ExecToolCallOutput {
+            exit_code: 1,
+            stdout: StreamOutput::new(String::new()),
+            stderr: StreamOutput::new("cargo: no such command: frob\n".to_string()),
+            aggregated_output: StreamOutput::new("cargo: no such command: frob\n".to_string()),
+            duration: Duration::from_millis(120),
+            timed_out: false,
+        };
+        let command = vec!["cargo".to_string(), "frob".to_string()];
+        let items = vec![ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload {
+                content: format_exec_output_for_model(
+                    &exec_output,
+                    ExecOutputFormat::Combined,
+                    &command,
+                ),
+                success: Some(false),
+            },
+        }];
+
+        let markdown = render_transcript(&items, TranscriptFormat::Markdown).unwrap();
+
+        assert_eq!(
+            "# Conversation transcript\n\n\
+             ### Tool output (exit code 1)\n\n```\ncargo: no such command: frob\n```\n",
+            markdown
+        );
+    }
+
+    #[test]
+    fn markdown_export_skips_compaction_summaries() {
+        let mut items = build_compacted_history(
+            vec![message(
+                "user",
+                "<user_instructions>be brief</user_instructions>",
+            )],
+            &["fix the bug".to_string()],
+            "Fixed the bug.",
+            Some("2025-01-01T00:00:00Z"),
+            Some("test-model"),
+        );
+        items.extend([
+            message("user", "a quick tangent"),
+            message("assistant", "tangent resolved"),
+        ]);
+        let tangent = items.len() - 2..items.len();
+        items = splice_range_summary(items, tangent, "Resolved a tangent.");
+        items.extend([
+            message("user", "now add a test"),
+            message("assistant", "Added one."),
+        ]);
+
+        let markdown = render_transcript(&items, TranscriptFormat::Markdown).unwrap();
+
+        assert_eq!(
+            "# Conversation transcript\n\n\
+             ## User\n\nnow add a test\n\n\
+             ## Assistant\n\nAdded one.\n",
+            markdown
+        );
+    }
+
+    #[test]
+    fn code_block_fence_outgrows_embedded_backticks() {
+        assert_eq!("````\nsee ```rust\n````", code_block("see ```rust"));
+    }
+
+    #[test]
+    fn json_export_tags_entries() {
+        let items = vec![message("user", "hi"), message("assistant", "hello")];
+
+        let json = render_transcript(&items, TranscriptFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            serde_json::json!([
+                { "type": "user", "text": "hi" },
+                { "type": "assistant", "text": "hello" },
+            ]),
+            value
+        );
+    }
+}
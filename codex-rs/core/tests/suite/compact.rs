@@ -3,15 +3,20 @@ use codex_core::ConversationManager;
 use codex_core::ModelProviderInfo;
 use codex_core::NewConversation;
 use codex_core::built_in_model_providers;
+use codex_core::protocol::AskForApproval;
+use codex_core::protocol::CompactionConfigResponseEvent;
 use codex_core::protocol::ErrorEvent;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::Op;
 use codex_core::protocol::RolloutItem;
 use codex_core::protocol::RolloutLine;
+use codex_core::protocol::SandboxPolicy;
 use codex_protocol::user_input::UserInput;
 use core_test_support::load_default_config_for_test;
 use core_test_support::skip_if_no_network;
+use core_test_support::test_codex::test_codex;
 use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
 use tempfile::TempDir;
 
 use codex_core::codex::compact::SUMMARIZATION_PROMPT;
@@ -751,6 +756,187 @@ async fn manual_compact_retries_after_context_window_error() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn manual_compact_uses_compaction_retry_budget() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+
+    let user_turn = sse(vec![
+        ev_assistant_message("m1", FIRST_REPLY),
+        ev_completed("r1"),
+    ]);
+    // Stream ends before `response.completed`, which is a retryable error.
+    let compact_dropped = sse(vec![ev_assistant_message("m2", SUMMARY_TEXT)]);
+    let compact_succeeds = sse(vec![
+        ev_assistant_message("m3", SUMMARY_TEXT),
+        ev_completed("r3"),
+    ]);
+
+    let request_log =
+        mount_sse_sequence(&server, vec![user_turn, compact_dropped, compact_succeeds]).await;
+
+    let model_provider = ModelProviderInfo {
+        base_url: Some(format!("{}/v1", server.uri())),
+        request_max_retries: Some(0),
+        stream_max_retries: Some(0),
+        ..built_in_model_providers()["openai"].clone()
+    };
+
+    let home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&home);
+    config.model_provider = model_provider;
+    config.compaction_max_retries = Some(1);
+    let codex = ConversationManager::with_auth(CodexAuth::from_api_key("dummy"))
+        .new_conversation(config)
+        .await
+        .unwrap()
+        .conversation;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "first turn".into(),
+            }],
+        })
+        .await
+        .unwrap();
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    codex.submit(Op::Compact).await.unwrap();
+
+    let EventMsg::AgentMessage(message) = wait_for_event(&codex, |ev| {
+        matches!(ev, EventMsg::AgentMessage(_) | EventMsg::Error(_))
+    })
+    .await
+    else {
+        panic!("compaction should succeed after one retry");
+    };
+    assert_eq!(message.message, "Compact task completed");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    assert_eq!(
+        request_log.requests().len(),
+        3,
+        "expected user turn and two compact attempts"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn tool_output_compaction_uses_compaction_retry_budget() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+
+    let call_id = "call-large-output";
+    let args = serde_json::json!({
+        "command": ["/bin/sh", "-c", "head -c 20000 /dev/zero | tr '\\0' x"],
+        "timeout_ms": 5_000,
+    });
+    let tool_call = sse(vec![
+        ev_function_call(call_id, "shell", &args.to_string()),
+        ev_completed("r1"),
+    ]);
+    let tool_reply = sse(vec![
+        ev_assistant_message("m1", FIRST_REPLY),
+        ev_completed("r2"),
+    ]);
+    // Stream ends before `response.completed`, which is a retryable error.
+    let summary_dropped = sse(vec![ev_assistant_message("m2", SUMMARY_TEXT)]);
+    let summary_succeeds = sse(vec![
+        ev_assistant_message("m3", SUMMARY_TEXT),
+        ev_completed("r4"),
+    ]);
+    let request_log = mount_sse_sequence(
+        &server,
+        vec![tool_call, tool_reply, summary_dropped, summary_succeeds],
+    )
+    .await;
+
+    let test = test_codex()
+        .with_config(|config| {
+            config.model_provider.request_max_retries = Some(0);
+            config.model_provider.stream_max_retries = Some(0);
+            config.compaction_max_retries = Some(1);
+            config.approval_policy = AskForApproval::Never;
+            config.sandbox_policy = SandboxPolicy::DangerFullAccess;
+        })
+        .build(&server)
+        .await
+        .unwrap();
+    let codex = test.codex.clone();
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "print a lot of output".into(),
+            }],
+        })
+        .await
+        .unwrap();
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    codex.submit(Op::CompactToolOutputs).await.unwrap();
+    let EventMsg::BackgroundEvent(event) = wait_for_event(&codex, |ev| match ev {
+        EventMsg::BackgroundEvent(event) => event.message.starts_with("Summarized"),
+        EventMsg::Error(_) => true,
+        _ => false,
+    })
+    .await
+    else {
+        panic!("tool output compaction should succeed after one retry");
+    };
+    assert_eq!("Summarized 1 large tool output(s).", event.message);
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    let requests = request_log.requests();
+    assert_eq!(
+        requests.len(),
+        4,
+        "expected tool call, tool reply and two summary attempts"
+    );
+    assert_eq!(requests[2].input(), requests[3].input());
+    let summary_prompt = requests[3].input()[0]["content"][0]["text"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    assert!(
+        summary_prompt.contains("Tool: shell"),
+        "summary request should describe the tool call: {summary_prompt}"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn get_compaction_config_reports_effective_settings() {
+    let home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&home);
+    config.model_auto_compact_token_limit = Some(123_456);
+    config.model_context_window = Some(200_000);
+    config.compaction_max_retries = Some(7);
+    let context_window_percent = config.model_family.effective_context_window_percent;
+    let codex = ConversationManager::with_auth(CodexAuth::from_api_key("dummy"))
+        .new_conversation(config)
+        .await
+        .unwrap()
+        .conversation;
+
+    codex.submit(Op::GetCompactionConfig).await.unwrap();
+
+    let response = wait_for_event_match(&codex, |ev| match ev {
+        EventMsg::CompactionConfigResponse(response) => Some(*response),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        CompactionConfigResponseEvent {
+            auto_compact_token_limit: Some(123_456),
+            model_context_window: Some(200_000 * context_window_percent / 100),
+            max_retries: 7,
+        },
+        response
+    );
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn auto_compact_allows_multiple_attempts_when_interleaved_with_other_turn_events() {
     skip_if_no_network!();
@@ -956,3 +1142,96 @@ async fn auto_compact_triggers_after_function_call_over_95_percent_usage() {
         "auto compact request should include the summarization prompt after exceeding 95% (limit {limit})"
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn compact_range_splices_summary_in_place() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+
+    let first_turn = sse(vec![
+        ev_assistant_message("m1", FIRST_REPLY),
+        ev_completed("r1"),
+    ]);
+    let range_summary = sse(vec![
+        ev_assistant_message("m2", SUMMARY_TEXT),
+        ev_completed("r2"),
+    ]);
+    let next_turn = sse(vec![ev_completed("r3")]);
+    let request_log = mount_sse_sequence(&server, vec![first_turn, range_summary, next_turn]).await;
+
+    let model_provider = ModelProviderInfo {
+        base_url: Some(format!("{}/v1", server.uri())),
+        ..built_in_model_providers()["openai"].clone()
+    };
+    let home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&home);
+    config.model_provider = model_provider;
+    let codex = ConversationManager::with_auth(CodexAuth::from_api_key("dummy"))
+        .new_conversation(config)
+        .await
+        .unwrap()
+        .conversation;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: "a quick tangent".into(),
+            }],
+        })
+        .await
+        .unwrap();
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    // Compact the tangent: the user message and the reply to it.
+    let initial_context = request_log.requests()[0].input();
+    let start = initial_context.len() - 1;
+    let end = start + 2;
+    codex.submit(Op::CompactRange { start, end }).await.unwrap();
+    let EventMsg::BackgroundEvent(event) = wait_for_event(&codex, |ev| {
+        matches!(ev, EventMsg::BackgroundEvent(_) | EventMsg::Error(_))
+    })
+    .await
+    else {
+        panic!("range compaction should succeed");
+    };
+    assert_eq!(
+        format!("Compacted history items {start}..{end}."),
+        event.message
+    );
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    codex
+        .submit(Op::UserInput {
+            items: vec![UserInput::Text {
+                text: THIRD_USER_MSG.into(),
+            }],
+        })
+        .await
+        .unwrap();
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TaskComplete(_))).await;
+
+    let requests = request_log.requests();
+    assert_eq!(requests.len(), 3, "expected turn, range summary, turn");
+
+    let summary_input = requests[1].input();
+    assert_eq!(initial_context[start..], summary_input[..1]);
+    assert_eq!(
+        summary_input.last().unwrap()["content"][0]["text"].as_str(),
+        Some(SUMMARIZATION_PROMPT)
+    );
+
+    let message = |text: &str| {
+        serde_json::json!({
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_text", "text": text }],
+        })
+    };
+    let mut expected = initial_context[..start].to_vec();
+    expected.push(message(&format!(
+        "Part of the earlier conversation was compacted. Here is a summary of the removed items:\n\n{SUMMARY_TEXT}"
+    )));
+    expected.push(message(THIRD_USER_MSG));
+    assert_eq!(expected, requests[2].input());
+}
@@ -37,6 +37,8 @@ use tempfile::TempDir;
 use wiremock::MockServer;
 
 const AFTER_SECOND_RESUME: &str = "AFTER_SECOND_RESUME";
+const PROVENANCE_PREFIX: &str = "This summary was produced by ";
+const COMPACTED_AT_PLACEHOLDER: &str = "<compacted_at>";
 
 fn network_disabled() -> bool {
     std::env::var(CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR).is_ok()
@@ -282,13 +284,7 @@ async fn compact_resume_and_fork_preserve_model_history_view() {
           "content": [
             {
               "type": "input_text",
-              "text": "You were originally given instructions from a user over one or more turns. Here were the user messages:
-
-hello world
-
-Another language model started to solve this problem and produced a summary of its thinking process. You also have access to the state of the tools that were used by that language model. Use this to build on the work that has already been done and avoid duplicating work. Here is the summary produced by the other language model, use the information in this summary to assist with your own analysis:
-
-SUMMARY_ONLY_CONTEXT"
+              "text": summary_bridge_text("hello world")
             }
           ]
         },
@@ -347,13 +343,7 @@ SUMMARY_ONLY_CONTEXT"
           "content": [
             {
               "type": "input_text",
-              "text": "You were originally given instructions from a user over one or more turns. Here were the user messages:
-
-hello world
-
-Another language model started to solve this problem and produced a summary of its thinking process. You also have access to the state of the tools that were used by that language model. Use this to build on the work that has already been done and avoid duplicating work. Here is the summary produced by the other language model, use the information in this summary to assist with your own analysis:
-
-SUMMARY_ONLY_CONTEXT"
+              "text": summary_bridge_text("hello world")
             }
           ]
         },
@@ -432,13 +422,7 @@ SUMMARY_ONLY_CONTEXT"
           "content": [
             {
               "type": "input_text",
-              "text": "You were originally given instructions from a user over one or more turns. Here were the user messages:
-
-hello world
-
-Another language model started to solve this problem and produced a summary of its thinking process. You also have access to the state of the tools that were used by that language model. Use this to build on the work that has already been done and avoid duplicating work. Here is the summary produced by the other language model, use the information in this summary to assist with your own analysis:
-
-SUMMARY_ONLY_CONTEXT"
+              "text": summary_bridge_text("hello world")
             }
           ]
         },
@@ -609,7 +593,7 @@ async fn compact_resume_after_second_compaction_preserves_history() {
             "content": [
               {
                 "type": "input_text",
-                "text": "You were originally given instructions from a user over one or more turns. Here were the user messages:\n\nAFTER_FORK\n\nAnother language model started to solve this problem and produced a summary of its thinking process. You also have access to the state of the tools that were used by that language model. Use this to build on the work that has already been done and avoid duplicating work. Here is the summary produced by the other language model, use the information in this summary to assist with your own analysis:\n\nSUMMARY_ONLY_CONTEXT"
+                "text": summary_bridge_text("AFTER_FORK")
               }
             ]
           },
@@ -665,6 +649,40 @@ fn normalize_line_endings(value: &mut Value) {
     }
 }
 
+/// Bridge message that compaction appends after summarizing `user_messages`,
+/// with the timestamp replaced by `COMPACTED_AT_PLACEHOLDER`.
+fn summary_bridge_text(user_messages: &str) -> String {
+    format!(
+        "You were originally given instructions from a user over one or more turns. Here were the user messages:\n\n{user_messages}\n\nAnother language model started to solve this problem and produced a summary of its thinking process. You also have access to the state of the tools that were used by that language model. Use this to build on the work that has already been done and avoid duplicating work. Here is the summary produced by the other language model, use the information in this summary to assist with your own analysis:\n\n{SUMMARY_TEXT}\n\n{PROVENANCE_PREFIX}{OPENAI_DEFAULT_MODEL} at {COMPACTED_AT_PLACEHOLDER}."
+    )
+}
+
+/// Replaces the wall-clock timestamp in bridge messages so requests can be
+/// compared against fixed expectations.
+fn normalize_compacted_at(value: &mut Value) {
+    match value {
+        Value::String(text) => {
+            if let Some(start) = text.rfind(PROVENANCE_PREFIX)
+                && let Some(at) = text[start..].rfind(" at ")
+            {
+                text.truncate(start + at);
+                text.push_str(&format!(" at {COMPACTED_AT_PLACEHOLDER}."));
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                normalize_compacted_at(item);
+            }
+        }
+        Value::Object(map) => {
+            for item in map.values_mut() {
+                normalize_compacted_at(item);
+            }
+        }
+        _ => {}
+    }
+}
+
 async fn gather_request_bodies(server: &MockServer) -> Vec<Value> {
     server
         .received_requests()
@@ -674,6 +692,7 @@ async fn gather_request_bodies(server: &MockServer) -> Vec<Value> {
         .map(|req| {
             let mut value = req.body_json::<Value>().expect("valid JSON body");
             normalize_line_endings(&mut value);
+            normalize_compacted_at(&mut value);
             value
         })
         .collect()
@@ -1,10 +1,15 @@
+use codex_core::AuthManager;
 use codex_core::CodexAuth;
 use codex_core::ConversationManager;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::Op;
+use codex_core::protocol::ReasoningEffortResponseEvent;
+use codex_core::protocol::RolloutItem;
+use codex_core::protocol::RolloutLine;
 use codex_core::protocol_config_types::ReasoningEffort;
 use core_test_support::load_default_config_for_test;
 use core_test_support::wait_for_event;
+use core_test_support::wait_for_event_match;
 use pretty_assertions::assert_eq;
 use tempfile::TempDir;
 
@@ -90,3 +95,189 @@ async fn override_turn_context_does_not_create_config_file() {
         "override should not create config.toml"
     );
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn set_reasoning_effort_updates_and_persists_turn_context() {
+    let codex_home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&codex_home);
+    config.model_reasoning_effort = Some(ReasoningEffort::Low);
+
+    let conversation_manager =
+        ConversationManager::with_auth(CodexAuth::from_api_key("Test API Key"));
+    let new_conversation = conversation_manager
+        .new_conversation(config)
+        .await
+        .expect("create conversation");
+    let codex = new_conversation.conversation;
+    let rollout_path = new_conversation.session_configured.rollout_path;
+
+    codex
+        .submit(Op::SetReasoningEffort {
+            effort: Some(ReasoningEffort::High),
+        })
+        .await
+        .expect("submit effort change");
+    let changed = wait_for_event_match(&codex, |ev| match ev {
+        EventMsg::TurnContextChanged(item) => Some(item.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(Some(ReasoningEffort::High), changed.effort);
+
+    codex
+        .submit(Op::GetReasoningEffort)
+        .await
+        .expect("submit effort query");
+    let response = wait_for_event_match(&codex, |ev| match ev {
+        EventMsg::ReasoningEffortResponse(response) => Some(response.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        ReasoningEffortResponseEvent {
+            effort: Some(ReasoningEffort::High),
+        },
+        response
+    );
+
+    codex.submit(Op::Shutdown).await.expect("request shutdown");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::ShutdownComplete)).await;
+
+    let text = std::fs::read_to_string(&rollout_path).expect("read rollout");
+    let persisted_efforts: Vec<Option<ReasoningEffort>> = text
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RolloutLine>(line).ok())
+        .filter_map(|line| match line.item {
+            RolloutItem::TurnContext(item) => Some(item.effort),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![Some(ReasoningEffort::High)], persisted_efforts);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn set_model_switches_known_models_and_rejects_unknown() {
+    let codex_home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&codex_home);
+    config.model = "gpt-4.1".to_string();
+
+    let conversation_manager =
+        ConversationManager::with_auth(CodexAuth::from_api_key("Test API Key"));
+    let new_conversation = conversation_manager
+        .new_conversation(config)
+        .await
+        .expect("create conversation");
+    let codex = new_conversation.conversation;
+    let rollout_path = new_conversation.session_configured.rollout_path;
+
+    codex
+        .submit(Op::SetModel {
+            model: "o3".to_string(),
+        })
+        .await
+        .expect("submit model change");
+    let changed = wait_for_event_match(&codex, |ev| match ev {
+        EventMsg::TurnContextChanged(item) => Some(item.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!("o3", changed.model);
+
+    codex
+        .submit(Op::SetModel {
+            model: "not-a-real-model".to_string(),
+        })
+        .await
+        .expect("submit unknown model");
+    let error = wait_for_event_match(&codex, |ev| match ev {
+        EventMsg::Error(err) => Some(err.message.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        "unsupported operation: unknown model: not-a-real-model",
+        error
+    );
+
+    codex.submit(Op::Shutdown).await.expect("request shutdown");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::ShutdownComplete)).await;
+
+    let text = std::fs::read_to_string(&rollout_path).expect("read rollout");
+    let persisted_models: Vec<String> = text
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RolloutLine>(line).ok())
+        .filter_map(|line| match line.item {
+            RolloutItem::TurnContext(item) => Some(item.model),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec!["o3".to_string()], persisted_models);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn resume_restores_model_and_effort_overrides() {
+    let codex_home = TempDir::new().unwrap();
+    let mut config = load_default_config_for_test(&codex_home);
+    config.model = "gpt-4.1".to_string();
+    config.model_reasoning_effort = Some(ReasoningEffort::Low);
+
+    let conversation_manager =
+        ConversationManager::with_auth(CodexAuth::from_api_key("Test API Key"));
+    let new_conversation = conversation_manager
+        .new_conversation(config.clone())
+        .await
+        .expect("create conversation");
+    let codex = new_conversation.conversation;
+    let rollout_path = new_conversation.session_configured.rollout_path;
+
+    codex
+        .submit(Op::SetModel {
+            model: "o3".to_string(),
+        })
+        .await
+        .expect("submit model change");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnContextChanged(_))).await;
+    codex
+        .submit(Op::SetReasoningEffort {
+            effort: Some(ReasoningEffort::High),
+        })
+        .await
+        .expect("submit effort change");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnContextChanged(_))).await;
+
+    codex.submit(Op::Shutdown).await.expect("request shutdown");
+    wait_for_event(&codex, |ev| matches!(ev, EventMsg::ShutdownComplete)).await;
+
+    let resumed = conversation_manager
+        .resume_conversation_from_rollout(
+            config,
+            rollout_path,
+            AuthManager::from_auth_for_testing(CodexAuth::from_api_key("Test API Key")),
+        )
+        .await
+        .expect("resume conversation");
+    assert_eq!(
+        ("o3".to_string(), Some(ReasoningEffort::High)),
+        (
+            resumed.session_configured.model,
+            resumed.session_configured.reasoning_effort
+        )
+    );
+
+    resumed
+        .conversation
+        .submit(Op::GetReasoningEffort)
+        .await
+        .expect("submit effort query");
+    let response = wait_for_event_match(&resumed.conversation, |ev| match ev {
+        EventMsg::ReasoningEffortResponse(response) => Some(response.clone()),
+        _ => None,
+    })
+    .await;
+    assert_eq!(
+        ReasoningEffortResponseEvent {
+            effort: Some(ReasoningEffort::High),
+        },
+        response
+    );
+}
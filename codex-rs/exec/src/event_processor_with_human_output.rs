@@ -490,6 +490,12 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             EventMsg::ListCustomPromptsResponse(_) => {
                 // Currently ignored in exec output.
             }
+            EventMsg::CompactionStatsResponse(_)
+            | EventMsg::CompactionConfigResponse(_)
+            | EventMsg::TurnContextChanged(_)
+            | EventMsg::ReasoningEffortResponse(_) => {
+                // Currently ignored in exec output.
+            }
             EventMsg::ViewImageToolCall(view) => {
                 ts_msg!(
                     self,
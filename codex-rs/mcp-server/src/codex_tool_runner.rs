@@ -266,6 +266,10 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::McpToolCallEnd(_)
                     | EventMsg::McpListToolsResponse(_)
                     | EventMsg::ListCustomPromptsResponse(_)
+                    | EventMsg::CompactionStatsResponse(_)
+                    | EventMsg::CompactionConfigResponse(_)
+                    | EventMsg::TurnContextChanged(_)
+                    | EventMsg::ReasoningEffortResponse(_)
                     | EventMsg::ExecCommandBegin(_)
                     | EventMsg::ExecCommandOutputDelta(_)
                     | EventMsg::ExecCommandEnd(_)
@@ -255,6 +255,10 @@ pub struct ShellToolCallParams {
     pub with_escalated_permissions: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub justification: Option<String>,
+    /// Extra environment variables for this command only, applied on top of
+    /// the shell environment policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Clone, PartialEq, JsonSchema, TS)]
@@ -373,6 +377,7 @@ mod tests {
                 timeout_ms: Some(1000),
                 with_escalated_permissions: None,
                 justification: None,
+                env: None,
             },
             params
         );
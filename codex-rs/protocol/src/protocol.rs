@@ -132,6 +132,22 @@ pub enum Op {
         summary: Option<ReasoningSummaryConfig>,
     },
 
+    /// Change the reasoning effort used for subsequent turns. `None` clears
+    /// the effort so the model's default applies. The updated settings are
+    /// reported via `EventMsg::TurnContextChanged`.
+    SetReasoningEffort {
+        effort: Option<ReasoningEffortConfig>,
+    },
+
+    /// Switch the model used for subsequent turns. Unknown models are rejected
+    /// with `EventMsg::Error`; otherwise the updated settings are reported via
+    /// `EventMsg::TurnContextChanged`.
+    SetModel { model: String },
+
+    /// Request the reasoning effort used for subsequent turns.
+    /// Reply is delivered via `EventMsg::ReasoningEffortResponse`.
+    GetReasoningEffort,
+
     /// Approve a command execution
     ExecApproval {
         /// The id of the submission we are approving
@@ -171,11 +187,37 @@ pub enum Op {
     /// Request the list of available custom prompts.
     ListCustomPrompts,
 
+    /// Request how often full-history compaction ran in this session and how
+    /// much it saved. Reply is delivered via `EventMsg::CompactionStatsResponse`.
+    GetCompactionStats,
+
+    /// Request the compaction settings that apply to the next turn.
+    /// Reply is delivered via `EventMsg::CompactionConfigResponse`.
+    GetCompactionConfig,
+
     /// Request the agent to summarize the current conversation context.
     /// The agent will use its existing context (either conversation history or previous response id)
     /// to generate a summary which will be returned as an AgentMessage event.
     Compact,
 
+    /// Summarize oversized tool outputs in place, leaving the rest of the
+    /// conversation history untouched. Cheaper than a full `Compact`.
+    CompactToolOutputs,
+
+    /// Summarize conversation history items `[start, end)` into a single
+    /// message spliced in their place. The range is widened so tool calls stay
+    /// paired with their outputs; the rest of the history is left untouched.
+    CompactRange { start: usize, end: usize },
+
+    /// Write the current conversation history to `path` as a readable
+    /// transcript. Relative paths are resolved against the session's `cwd`.
+    /// Completion is reported via `EventMsg::BackgroundEvent`, failures via
+    /// `EventMsg::Error`.
+    ExportTranscript {
+        path: PathBuf,
+        format: TranscriptFormat,
+    },
+
     /// Request a code review from the agent.
     Review { review_request: ReviewRequest },
 
@@ -506,6 +548,18 @@ pub enum EventMsg {
     /// List of custom prompts available to the agent.
     ListCustomPromptsResponse(ListCustomPromptsResponseEvent),
 
+    /// Compaction totals for the session, in reply to `Op::GetCompactionStats`.
+    CompactionStatsResponse(CompactionStatsResponseEvent),
+
+    /// Effective compaction settings, in reply to `Op::GetCompactionConfig`.
+    CompactionConfigResponse(CompactionConfigResponseEvent),
+
+    /// Settings that apply to subsequent turns changed mid-session.
+    TurnContextChanged(TurnContextItem),
+
+    /// Current reasoning effort, in reply to `Op::GetReasoningEffort`.
+    ReasoningEffortResponse(ReasoningEffortResponseEvent),
+
     PlanUpdate(UpdatePlanArgs),
 
     TurnAborted(TurnAbortedEvent),
@@ -970,6 +1024,16 @@ pub enum RolloutItem {
 #[derive(Serialize, Deserialize, Clone, Debug, JsonSchema, TS)]
 pub struct CompactedItem {
     pub message: String,
+    /// RFC 3339 timestamp of when the summary was produced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compacted_at: Option<String>,
+    /// Model that produced the summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// History to restore verbatim on resume, for compactions that rewrite
+    /// individual items instead of summarizing the whole conversation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement_history: Option<Vec<ResponseItem>>,
 }
 
 impl From<CompactedItem> for ResponseItem {
@@ -1244,6 +1308,39 @@ pub struct ListCustomPromptsResponseEvent {
     pub custom_prompts: Vec<CustomPrompt>,
 }
 
+/// Response payload for `Op::GetCompactionStats`.
+///
+/// Only full-history compactions (`Op::Compact` and auto-compaction) are
+/// counted. `Op::CompactRange` and `Op::CompactToolOutputs` rewrite part of
+/// the history in place and are not reflected in these totals.
+#[derive(Debug, Default, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct CompactionStatsResponseEvent {
+    /// Number of full-history compactions that completed in this session.
+    pub count: u64,
+    /// History items removed across all compactions.
+    pub total_items_dropped: u64,
+    /// Estimated prompt tokens removed across all compactions.
+    pub total_tokens_saved: u64,
+}
+
+/// Response payload for `Op::GetCompactionConfig`.
+#[derive(Debug, Default, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct CompactionConfigResponseEvent {
+    /// Total token usage that triggers auto-compaction; `None` disables it.
+    pub auto_compact_token_limit: Option<i64>,
+    /// Usable context window of the current model, if known.
+    pub model_context_window: Option<i64>,
+    /// Retries allowed for a compaction request that fails to stream.
+    pub max_retries: u64,
+}
+
+/// Response payload for `Op::GetReasoningEffort`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct ReasoningEffortResponseEvent {
+    /// `None` when no effort is set and the model's default applies.
+    pub effort: Option<ReasoningEffortConfig>,
+}
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct SessionConfiguredEvent {
     /// Name left as session_id instead of conversation_id for backwards compatibility.
@@ -1270,6 +1367,14 @@ pub struct SessionConfiguredEvent {
     pub rollout_path: PathBuf,
 }
 
+/// Output format for [`Op::ExportTranscript`].
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptFormat {
+    Markdown,
+    Json,
+}
+
 /// User's decision in response to an ExecApprovalRequest.
 #[derive(
     Debug, Default, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Display, JsonSchema, TS,
@@ -231,6 +231,7 @@ impl App {
                     {
                         return Ok(true);
                     }
+                    self.chat_widget.handle_idle_tip_tick();
                     tui.draw(
                         self.chat_widget.desired_height(tui.terminal.size()?.width),
                         |frame| {
@@ -428,6 +429,9 @@ impl App {
             AppEvent::OpenReviewCommitPicker(cwd) => {
                 self.chat_widget.show_review_commit_picker(&cwd).await;
             }
+            AppEvent::StartReviewFile { cwd, path } => {
+                self.chat_widget.start_review_file(&cwd, &path).await;
+            }
             AppEvent::OpenReviewCustomPrompt => {
                 self.chat_widget.show_review_custom_prompt();
             }
@@ -99,6 +99,12 @@ pub(crate) enum AppEvent {
     /// Open the custom prompt option from the review popup.
     OpenReviewCustomPrompt,
 
+    /// Resolve the base branch in `cwd` and start a review of `path`.
+    StartReviewFile {
+        cwd: PathBuf,
+        path: String,
+    },
+
     /// Open the approval popup.
     FullScreenApprovalRequest(ApprovalRequest),
 }
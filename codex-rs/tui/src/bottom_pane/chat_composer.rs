@@ -23,6 +23,7 @@ use super::footer::FooterMode;
 use super::footer::FooterProps;
 use super::footer::esc_hint_mode;
 use super::footer::footer_height;
+use super::footer::footer_tip;
 use super::footer::render_footer;
 use super::footer::reset_mode_after_activity;
 use super::footer::toggle_shortcut_mode;
@@ -109,6 +110,10 @@ pub(crate) struct ChatComposer {
     footer_mode: FooterMode,
     footer_hint_override: Option<Vec<(String, String)>>,
     context_window_percent: Option<i64>,
+    // When set, rotate footer tips after the composer has been idle this long.
+    idle_tip_delay: Option<Duration>,
+    idle_since: Instant,
+    next_tip_index: usize,
 }
 
 /// Popup state – at most one can be visible at any time.
@@ -152,6 +157,9 @@ impl ChatComposer {
             footer_mode: FooterMode::ShortcutSummary,
             footer_hint_override: None,
             context_window_percent: None,
+            idle_tip_delay: None,
+            idle_since: Instant::now(),
+            next_tip_index: 0,
         };
         // Apply configuration via the setter to keep side-effects centralized.
         this.set_disable_paste_burst(disable_paste_burst);
@@ -162,7 +170,7 @@ impl ChatComposer {
         let footer_props = self.footer_props();
         let footer_hint_height = self
             .custom_footer_height()
-            .unwrap_or_else(|| footer_height(footer_props));
+            .unwrap_or_else(|| footer_height(footer_props, width));
         let footer_spacing = Self::footer_spacing(footer_hint_height);
         let footer_total_height = footer_hint_height + footer_spacing;
         const COLS_WITH_MARGIN: u16 = LIVE_PREFIX_COLS + 1;
@@ -180,7 +188,7 @@ impl ChatComposer {
         let footer_props = self.footer_props();
         let footer_hint_height = self
             .custom_footer_height()
-            .unwrap_or_else(|| footer_height(footer_props));
+            .unwrap_or_else(|| footer_height(footer_props, area.width));
         let footer_spacing = Self::footer_spacing(footer_hint_height);
         let footer_total_height = footer_hint_height + footer_spacing;
         let popup_constraint = match &self.active_popup {
@@ -247,6 +255,8 @@ impl ChatComposer {
     }
 
     pub fn handle_paste(&mut self, pasted: String) -> bool {
+        self.note_activity();
+        self.footer_mode = reset_mode_after_activity(self.footer_mode);
         let char_count = pasted.chars().count();
         if char_count > LARGE_PASTE_CHAR_THRESHOLD {
             let placeholder = format!("[Pasted Content {char_count} chars]");
@@ -297,6 +307,47 @@ impl ChatComposer {
         }
     }
 
+    /// Enable (or disable with `None`) rotating footer tips once the composer
+    /// has been idle for `delay`.
+    pub(crate) fn set_idle_tip_delay(&mut self, delay: Option<Duration>) {
+        self.idle_tip_delay = delay;
+        self.idle_since = Instant::now();
+    }
+
+    /// Show the next footer tip if the composer has been idle long enough.
+    /// Returns true when the footer changed and a redraw is needed.
+    pub(crate) fn on_idle_tick(&mut self, now: Instant) -> bool {
+        let Some(deadline) = self.next_idle_tip_deadline() else {
+            return false;
+        };
+        if now < deadline {
+            return false;
+        }
+        self.footer_mode = FooterMode::Tip(footer_tip(self.next_tip_index));
+        self.next_tip_index = self.next_tip_index.wrapping_add(1);
+        self.idle_since = now;
+        true
+    }
+
+    /// When the next idle tip is due, or `None` if tips are disabled or the
+    /// composer is not currently idle.
+    pub(crate) fn next_idle_tip_deadline(&self) -> Option<Instant> {
+        let delay = self.idle_tip_delay?;
+        let idle = self.is_empty()
+            && !self.popup_active()
+            && !self.is_task_running
+            && !self.ctrl_c_quit_hint
+            && matches!(
+                self.footer_mode,
+                FooterMode::ShortcutSummary | FooterMode::Tip(_)
+            );
+        idle.then_some(self.idle_since + delay)
+    }
+
+    fn note_activity(&mut self) {
+        self.idle_since = Instant::now();
+    }
+
     /// Override the footer hint items displayed beneath the composer. Passing
     /// `None` restores the default shortcut footer.
     pub(crate) fn set_footer_hint_override(&mut self, items: Option<Vec<(String, String)>>) {
@@ -397,6 +448,7 @@ impl ChatComposer {
 
     /// Handle a key event coming from the main UI.
     pub fn handle_key_event(&mut self, key_event: KeyEvent) -> (InputResult, bool) {
+        self.note_activity();
         let result = match &mut self.active_popup {
             ActivePopup::Command(_) => self.handle_key_event_with_slash_popup(key_event),
             ActivePopup::File(_) => self.handle_key_event_with_file_popup(key_event),
@@ -1513,6 +1565,10 @@ impl ChatComposer {
 
     pub fn set_task_running(&mut self, running: bool) {
         self.is_task_running = running;
+        if matches!(self.footer_mode, FooterMode::Tip(_)) {
+            self.footer_mode = FooterMode::ShortcutSummary;
+        }
+        self.note_activity();
     }
 
     pub(crate) fn set_context_window_percent(&mut self, percent: Option<i64>) {
@@ -1545,7 +1601,7 @@ impl WidgetRef for ChatComposer {
                 let footer_props = self.footer_props();
                 let custom_height = self.custom_footer_height();
                 let footer_hint_height =
-                    custom_height.unwrap_or_else(|| footer_height(footer_props));
+                    custom_height.unwrap_or_else(|| footer_height(footer_props, area.width));
                 let footer_spacing = Self::footer_spacing(footer_hint_height);
                 let hint_rect = if footer_spacing > 0 && footer_hint_height > 0 {
                     let [_, hint_rect] = Layout::vertical([
@@ -1741,7 +1797,7 @@ mod tests {
         );
         setup(&mut composer);
         let footer_props = composer.footer_props();
-        let footer_lines = footer_height(footer_props);
+        let footer_lines = footer_height(footer_props, width);
         let footer_spacing = ChatComposer::footer_spacing(footer_lines);
         let height = footer_lines + footer_spacing + 8;
         let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
@@ -1803,6 +1859,43 @@ mod tests {
         });
     }
 
+    #[test]
+    fn idle_tick_shows_tip_and_activity_clears_it() {
+        use crossterm::event::KeyCode;
+        use crossterm::event::KeyEvent;
+        use crossterm::event::KeyModifiers;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+
+        // Tips are opt-in.
+        assert!(!composer.on_idle_tick(Instant::now() + Duration::from_secs(3600)));
+        assert_eq!(FooterMode::ShortcutSummary, composer.footer_mode);
+
+        let delay = Duration::from_secs(30);
+        composer.set_idle_tip_delay(Some(delay));
+        let deadline = composer
+            .next_idle_tip_deadline()
+            .expect("idle composer should have a tip deadline");
+        assert!(!composer.on_idle_tick(deadline - Duration::from_secs(1)));
+        assert!(composer.on_idle_tick(deadline));
+        assert_eq!(FooterMode::Tip(footer_tip(0)), composer.footer_mode);
+
+        // The next tip rotates in after another idle period.
+        assert!(composer.on_idle_tick(deadline + delay));
+        assert_eq!(FooterMode::Tip(footer_tip(1)), composer.footer_mode);
+
+        let _ = composer.handle_key_event(KeyEvent::new(KeyCode::Char('h'), KeyModifiers::NONE));
+        assert_eq!(FooterMode::ShortcutSummary, composer.footer_mode);
+    }
+
     #[test]
     fn esc_hint_stays_hidden_with_draft_content() {
         use crossterm::event::KeyCode;
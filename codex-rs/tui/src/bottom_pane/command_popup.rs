@@ -265,6 +265,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn review_file_is_registered() {
+        let mut popup = CommandPopup::new(Vec::new());
+        popup.on_composer_text_change("/review-f".to_string());
+        match popup.selected_item() {
+            Some(CommandItem::Builtin(cmd)) => assert_eq!(cmd, SlashCommand::ReviewFile),
+            Some(CommandItem::UserPrompt(_)) => {
+                panic!("unexpected prompt selected for '/review-f'")
+            }
+            None => panic!("expected a selected command for '/review-f'"),
+        }
+    }
+
     #[test]
     fn model_is_first_suggestion_for_mo() {
         let mut popup = CommandPopup::new(Vec::new());
@@ -27,6 +27,22 @@ pub(crate) enum FooterMode {
     ShortcutOverlay,
     EscHint,
     ContextOnly,
+    /// A discoverability tip shown after the composer has been idle for a while.
+    Tip(&'static str),
+}
+
+/// Tips rotated through the footer while the composer sits idle.
+pub(crate) const FOOTER_TIPS: &[&str] = &[
+    "try /review to check your changes",
+    "use @ to mention files in your prompt",
+    "run /init to create an AGENTS.md for this project",
+    "use /compact to summarize a long conversation",
+    "press Esc twice to edit your previous message",
+];
+
+/// Returns the tip at `index`, wrapping around the tip list.
+pub(crate) fn footer_tip(index: usize) -> &'static str {
+    FOOTER_TIPS[index % FOOTER_TIPS.len()]
 }
 
 pub(crate) fn toggle_shortcut_mode(current: FooterMode, ctrl_c_hint: bool) -> FooterMode {
@@ -53,25 +69,29 @@ pub(crate) fn reset_mode_after_activity(current: FooterMode) -> FooterMode {
         FooterMode::EscHint
         | FooterMode::ShortcutOverlay
         | FooterMode::CtrlCReminder
-        | FooterMode::ContextOnly => FooterMode::ShortcutSummary,
+        | FooterMode::ContextOnly
+        | FooterMode::Tip(_) => FooterMode::ShortcutSummary,
         other => other,
     }
 }
 
-pub(crate) fn footer_height(props: FooterProps) -> u16 {
-    footer_lines(props).len() as u16
+/// Most columns the shortcut overlay spreads across when the width allows.
+const MAX_OVERLAY_COLUMNS: usize = 3;
+
+pub(crate) fn footer_height(props: FooterProps, width: u16) -> u16 {
+    footer_lines(props, width).len() as u16
 }
 
 pub(crate) fn render_footer(area: Rect, buf: &mut Buffer, props: FooterProps) {
     Paragraph::new(prefix_lines(
-        footer_lines(props),
+        footer_lines(props, area.width),
         " ".repeat(FOOTER_INDENT_COLS).into(),
         " ".repeat(FOOTER_INDENT_COLS).into(),
     ))
     .render(area, buf);
 }
 
-fn footer_lines(props: FooterProps) -> Vec<Line<'static>> {
+fn footer_lines(props: FooterProps, width: u16) -> Vec<Line<'static>> {
     // Show the context indicator on the left, appended after the primary hint
     // (e.g., "? for shortcuts"). Keep it visible even when typing (i.e., when
     // the shortcut hint is hidden). Hide it only for the multi-line
@@ -89,12 +109,21 @@ fn footer_lines(props: FooterProps) -> Vec<Line<'static>> {
             ]);
             vec![line]
         }
-        FooterMode::ShortcutOverlay => shortcut_overlay_lines(ShortcutsState {
-            use_shift_enter_hint: props.use_shift_enter_hint,
-            esc_backtrack_hint: props.esc_backtrack_hint,
-        }),
+        FooterMode::ShortcutOverlay => shortcut_overlay_lines(
+            ShortcutsState {
+                use_shift_enter_hint: props.use_shift_enter_hint,
+                esc_backtrack_hint: props.esc_backtrack_hint,
+            },
+            width,
+        ),
         FooterMode::EscHint => vec![esc_hint_line(props.esc_backtrack_hint)],
         FooterMode::ContextOnly => vec![context_window_line(props.context_window_percent)],
+        FooterMode::Tip(tip) => {
+            let mut line = context_window_line(props.context_window_percent);
+            line.push_span(" · ".dim());
+            line.push_span(format!("Tip: {tip}").dim());
+            vec![line]
+        }
     }
 }
 
@@ -137,7 +166,7 @@ fn esc_hint_line(esc_backtrack_hint: bool) -> Line<'static> {
     }
 }
 
-fn shortcut_overlay_lines(state: ShortcutsState) -> Vec<Line<'static>> {
+fn shortcut_overlay_lines(state: ShortcutsState, width: u16) -> Vec<Line<'static>> {
     let mut commands = Line::from("");
     let mut newline = Line::from("");
     let mut file_paths = Line::from("");
@@ -160,31 +189,49 @@ fn shortcut_overlay_lines(state: ShortcutsState) -> Vec<Line<'static>> {
         }
     }
 
-    let ordered = vec![
+    let entries = vec![
         commands,
         newline,
         file_paths,
         paste_image,
         edit_previous,
         quit,
-        Line::from(""),
         show_transcript,
     ];
 
-    build_columns(ordered)
+    // Use the widest layout whose measured rows fit the available width.
+    let available = usize::from(width).saturating_sub(FOOTER_INDENT_COLS);
+    (2..=MAX_OVERLAY_COLUMNS)
+        .rev()
+        .map(|columns| build_columns(overlay_column_entries(&entries, columns), columns))
+        .find(|lines| lines.iter().all(|line| line.width() <= available))
+        .unwrap_or_else(|| {
+            let entries = entries.into_iter().filter(|entry| entry.width() > 0);
+            build_columns(entries.collect(), 1)
+        })
+}
+
+/// Overlay entries laid out for `columns`. The two-column layout inserts a
+/// blank before the transcript shortcut so it lands in the right-hand column
+/// under "ctrl + c to exit"; other layouts need no spacer.
+fn overlay_column_entries(entries: &[Line<'static>], columns: usize) -> Vec<Line<'static>> {
+    let mut entries = entries.to_vec();
+    if columns == 2 {
+        entries.insert(entries.len() - 1, Line::from(""));
+    }
+    entries
 }
 
-fn build_columns(entries: Vec<Line<'static>>) -> Vec<Line<'static>> {
-    if entries.is_empty() {
+fn build_columns(entries: Vec<Line<'static>>, columns: usize) -> Vec<Line<'static>> {
+    if entries.is_empty() || columns == 0 {
         return Vec::new();
     }
 
-    const COLUMNS: usize = 2;
-    const COLUMN_PADDING: [usize; COLUMNS] = [4, 4];
+    const COLUMN_PADDING: usize = 4;
     const COLUMN_GAP: usize = 4;
 
-    let rows = entries.len().div_ceil(COLUMNS);
-    let target_len = rows * COLUMNS;
+    let rows = entries.len().div_ceil(columns);
+    let target_len = rows * columns;
     let mut entries = entries;
     if entries.len() < target_len {
         entries.extend(std::iter::repeat_n(
@@ -193,24 +240,24 @@ fn build_columns(entries: Vec<Line<'static>>) -> Vec<Line<'static>> {
         ));
     }
 
-    let mut column_widths = [0usize; COLUMNS];
+    let mut column_widths = vec![0usize; columns];
 
     for (idx, entry) in entries.iter().enumerate() {
-        let column = idx % COLUMNS;
+        let column = idx % columns;
         column_widths[column] = column_widths[column].max(entry.width());
     }
 
-    for (idx, width) in column_widths.iter_mut().enumerate() {
-        *width += COLUMN_PADDING[idx];
+    for width in &mut column_widths {
+        *width += COLUMN_PADDING;
     }
 
     entries
-        .chunks(COLUMNS)
+        .chunks(columns)
         .map(|chunk| {
             let mut line = Line::from("");
             for (col, entry) in chunk.iter().enumerate() {
                 line.extend(entry.spans.clone());
-                if col < COLUMNS - 1 {
+                if col < columns - 1 {
                     let target_width = column_widths[col];
                     let padding = target_width.saturating_sub(entry.width()) + COLUMN_GAP;
                     line.push_span(Span::from(" ".repeat(padding)));
@@ -375,12 +422,17 @@ const SHORTCUTS: &[ShortcutDescriptor] = &[
 mod tests {
     use super::*;
     use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
     use ratatui::Terminal;
     use ratatui::backend::TestBackend;
 
     fn snapshot_footer(name: &str, props: FooterProps) {
-        let height = footer_height(props).max(1);
-        let mut terminal = Terminal::new(TestBackend::new(80, height)).unwrap();
+        snapshot_footer_with_width(name, props, 80);
+    }
+
+    fn snapshot_footer_with_width(name: &str, props: FooterProps, width: u16) {
+        let height = footer_height(props, width).max(1);
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
         terminal
             .draw(|f| {
                 let area = Rect::new(0, 0, f.area().width, height);
@@ -468,5 +520,44 @@ mod tests {
                 context_window_percent: Some(72),
             },
         );
+
+        snapshot_footer(
+            "footer_tip",
+            FooterProps {
+                mode: FooterMode::Tip(footer_tip(0)),
+                esc_backtrack_hint: false,
+                use_shift_enter_hint: false,
+                is_task_running: false,
+                context_window_percent: None,
+            },
+        );
+    }
+
+    #[test]
+    fn shortcut_overlay_columns_follow_width() {
+        let props = FooterProps {
+            mode: FooterMode::ShortcutOverlay,
+            esc_backtrack_hint: true,
+            use_shift_enter_hint: true,
+            is_task_running: false,
+            context_window_percent: None,
+        };
+
+        snapshot_footer_with_width("footer_shortcut_overlay_one_column", props, 50);
+        snapshot_footer_with_width("footer_shortcut_overlay_two_columns", props, 80);
+        snapshot_footer_with_width("footer_shortcut_overlay_three_columns", props, 100);
+    }
+
+    #[test]
+    fn activity_clears_tip() {
+        assert_eq!(
+            FooterMode::ShortcutSummary,
+            reset_mode_after_activity(FooterMode::Tip(footer_tip(1)))
+        );
+    }
+
+    #[test]
+    fn footer_tip_wraps_around() {
+        assert_eq!(footer_tip(0), footer_tip(FOOTER_TIPS.len()));
     }
 }
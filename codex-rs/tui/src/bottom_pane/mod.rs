@@ -13,6 +13,7 @@ use ratatui::layout::Layout;
 use ratatui::layout::Rect;
 use ratatui::widgets::WidgetRef;
 use std::time::Duration;
+use std::time::Instant;
 
 mod approval_overlay;
 pub(crate) use approval_overlay::ApprovalOverlay;
@@ -80,20 +81,23 @@ pub(crate) struct BottomPaneParams {
     pub(crate) enhanced_keys_supported: bool,
     pub(crate) placeholder_text: String,
     pub(crate) disable_paste_burst: bool,
+    pub(crate) idle_tip_delay: Option<Duration>,
 }
 
 impl BottomPane {
     const BOTTOM_PAD_LINES: u16 = 0;
     pub fn new(params: BottomPaneParams) -> Self {
         let enhanced_keys_supported = params.enhanced_keys_supported;
+        let mut composer = ChatComposer::new(
+            params.has_input_focus,
+            params.app_event_tx.clone(),
+            enhanced_keys_supported,
+            params.placeholder_text,
+            params.disable_paste_burst,
+        );
+        composer.set_idle_tip_delay(params.idle_tip_delay);
         Self {
-            composer: ChatComposer::new(
-                params.has_input_focus,
-                params.app_event_tx.clone(),
-                enhanced_keys_supported,
-                params.placeholder_text,
-                params.disable_paste_burst,
-            ),
+            composer,
             view_stack: Vec::new(),
             app_event_tx: params.app_event_tx,
             frame_requester: params.frame_requester,
@@ -466,6 +470,16 @@ impl BottomPane {
         self.composer.is_in_paste_burst()
     }
 
+    /// Rotate in a footer tip if the composer has been idle long enough, and
+    /// schedule a frame for when the next tip is due.
+    pub(crate) fn on_idle_tick(&mut self) {
+        let now = Instant::now();
+        self.composer.on_idle_tick(now);
+        if let Some(deadline) = self.composer.next_idle_tip_deadline() {
+            self.request_redraw_in(deadline.saturating_duration_since(now));
+        }
+    }
+
     pub(crate) fn on_history_entry_response(
         &mut self,
         log_id: u64,
@@ -571,6 +585,7 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            idle_tip_delay: None,
         });
         pane.push_approval_request(exec_request());
         assert_eq!(CancellationEvent::Handled, pane.on_ctrl_c());
@@ -591,6 +606,7 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            idle_tip_delay: None,
         });
 
         // Create an approval modal (active view).
@@ -622,6 +638,7 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            idle_tip_delay: None,
         });
 
         // Start a running task so the status indicator is active above the composer.
@@ -690,6 +707,7 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            idle_tip_delay: None,
         });
 
         // Begin a task: show initial status.
@@ -721,6 +739,7 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            idle_tip_delay: None,
         });
 
         // Activate spinner (status view replaces composer) with no live ring.
@@ -750,6 +769,7 @@ mod tests {
             enhanced_keys_supported: false,
             placeholder_text: "Ask Codex to do anything".to_string(),
             disable_paste_burst: false,
+            idle_tip_delay: None,
         });
 
         pane.set_task_running(true);
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use codex_core::config::Config;
 use codex_core::config_types::Notifications;
 use codex_core::git_info::current_branch_name;
+use codex_core::git_info::default_branch_base_ref;
 use codex_core::git_info::local_git_branches;
 use codex_core::project_doc::DEFAULT_PROJECT_DOC_FILENAME;
 use codex_core::protocol::AgentMessageDeltaEvent;
@@ -119,6 +120,7 @@ use codex_git_tooling::GitToolingError;
 use codex_git_tooling::create_ghost_commit;
 use codex_git_tooling::restore_ghost_commit;
 use codex_protocol::plan_tool::UpdatePlanArgs;
+use shlex::QuoteError;
 use strum::IntoEnumIterator;
 
 const MAX_TRACKED_GHOST_COMMITS: usize = 20;
@@ -927,6 +929,7 @@ impl ChatWidget {
                 enhanced_keys_supported,
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
+                idle_tip_delay: config.tui_idle_tip_delay,
             }),
             active_cell: None,
             config: config.clone(),
@@ -994,6 +997,7 @@ impl ChatWidget {
                 enhanced_keys_supported,
                 placeholder_text: placeholder,
                 disable_paste_burst: config.disable_paste_burst,
+                idle_tip_delay: config.tui_idle_tip_delay,
             }),
             active_cell: None,
             config: config.clone(),
@@ -1169,6 +1173,9 @@ impl ChatWidget {
             SlashCommand::Review => {
                 self.open_review_popup();
             }
+            SlashCommand::ReviewFile => {
+                self.show_review_file_prompt();
+            }
             SlashCommand::Model => {
                 self.open_model_popup();
             }
@@ -1276,6 +1283,11 @@ impl ChatWidget {
         }
     }
 
+    /// Rotate the idle footer tip when due; called on every draw tick.
+    pub(crate) fn handle_idle_tip_tick(&mut self) {
+        self.bottom_pane.on_idle_tick();
+    }
+
     fn flush_active_cell(&mut self) {
         if let Some(active) = self.active_cell.take() {
             self.needs_final_message_separator = true;
@@ -1498,7 +1510,12 @@ impl ChatWidget {
                 self.on_entered_review_mode(review_request)
             }
             EventMsg::ExitedReviewMode(review) => self.on_exited_review_mode(review),
-            EventMsg::ItemStarted(_) | EventMsg::ItemCompleted(_) => {}
+            EventMsg::ItemStarted(_)
+            | EventMsg::ItemCompleted(_)
+            | EventMsg::CompactionStatsResponse(_)
+            | EventMsg::CompactionConfigResponse(_)
+            | EventMsg::TurnContextChanged(_)
+            | EventMsg::ReasoningEffortResponse(_) => {}
         }
     }
 
@@ -2200,6 +2217,35 @@ impl ChatWidget {
         self.bottom_pane.show_view(Box::new(view));
     }
 
+    pub(crate) fn show_review_file_prompt(&mut self) {
+        let tx = self.app_event_tx.clone();
+        let cwd = self.config.cwd.clone();
+        let view = CustomPromptView::new(
+            "Review a file".to_string(),
+            "Type a file path and press Enter".to_string(),
+            None,
+            Box::new(move |path: String| {
+                let path = path.trim();
+                if path.is_empty() {
+                    return;
+                }
+                tx.send(AppEvent::StartReviewFile {
+                    cwd: cwd.clone(),
+                    path: path.to_string(),
+                });
+            }),
+        );
+        self.bottom_pane.show_view(Box::new(view));
+    }
+
+    pub(crate) async fn start_review_file(&mut self, cwd: &Path, path: &str) {
+        let base = default_branch_base_ref(cwd).await;
+        match review_file_request(path, base.as_deref()) {
+            Ok(review_request) => self.submit_op(Op::Review { review_request }),
+            Err(err) => self.add_error_message(format!("Cannot review '{path}': {err}")),
+        }
+    }
+
     /// Programmatically submit a user text message as if typed in the
     /// composer. The text will be added to conversation history and sent to
     /// the agent.
@@ -2360,6 +2406,35 @@ fn extract_first_bold(s: &str) -> Option<String> {
     None
 }
 
+/// Review request scoped to a single file's changes since it diverged from
+/// `base` (a locally available ref such as `origin/main`), or to its
+/// uncommitted changes when no base could be resolved.
+fn review_file_request(path: &str, base: Option<&str>) -> Result<ReviewRequest, QuoteError> {
+    let uncommitted = shlex::try_join(["git", "diff", "HEAD", "--", path])?;
+    let (diff_instructions, hint) = match base {
+        Some(base) => {
+            let range = format!("{base}...HEAD");
+            let committed = shlex::try_join(["git", "diff", range.as_str(), "--", path])?;
+            (
+                format!(
+                    "Run `{committed}` to see what changed since the merge base with `{base}`, and `{uncommitted}` for any uncommitted edits"
+                ),
+                format!("changes to '{path}' against {base}"),
+            )
+        }
+        None => (
+            format!("Run `{uncommitted}` to see what changed"),
+            format!("changes to '{path}'"),
+        ),
+    };
+    Ok(ReviewRequest {
+        prompt: format!(
+            "Review only the changes to `{path}`. {diff_instructions} (if the file is untracked, review its full contents). Provide prioritized, actionable findings limited to that file."
+        ),
+        user_facing_hint: hint,
+    })
+}
+
 #[cfg(test)]
 pub(crate) fn show_review_commit_picker_with_entries(
     chat: &mut ChatWidget,
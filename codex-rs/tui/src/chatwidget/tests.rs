@@ -257,6 +257,7 @@ fn make_chatwidget_manual() -> (
         enhanced_keys_supported: false,
         placeholder_text: "Ask Codex to do anything".to_string(),
         disable_paste_burst: false,
+        idle_tip_delay: None,
     });
     let auth_manager = AuthManager::from_auth_for_testing(CodexAuth::from_api_key("test"));
     let widget = ChatWidget {
@@ -926,6 +927,70 @@ fn custom_prompt_submit_sends_review_op() {
     }
 }
 
+/// `/review-file` asks for a path and sends a single review scoped to it.
+#[tokio::test]
+async fn review_file_command_sends_single_review_op() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual();
+
+    chat.dispatch_command(SlashCommand::ReviewFile);
+    chat.handle_paste(" src/lib.rs ".to_string());
+    chat.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let evt = rx.try_recv().expect("expected one app event");
+    let AppEvent::StartReviewFile { cwd, path } = evt else {
+        panic!("unexpected app event: {evt:?}");
+    };
+    assert_eq!(chat.config.cwd, cwd);
+    assert_eq!("src/lib.rs", path);
+    assert!(rx.try_recv().is_err(), "expected exactly one app event");
+
+    // Outside a git repository there is no base branch to diff against.
+    let not_a_repo = tempdir().expect("tempdir");
+    chat.start_review_file(not_a_repo.path(), &path).await;
+
+    match op_rx.try_recv() {
+        Ok(Op::Review { review_request }) => {
+            assert!(
+                review_request
+                    .prompt
+                    .contains("git diff HEAD -- src/lib.rs")
+            );
+            assert_eq!(
+                "changes to 'src/lib.rs'".to_string(),
+                review_request.user_facing_hint
+            );
+        }
+        other => panic!("unexpected op: {other:?}"),
+    }
+    assert!(op_rx.try_recv().is_err(), "expected exactly one op");
+}
+
+/// File reviews diff against the resolved base and quote the path.
+#[test]
+fn review_file_request_quotes_path_with_spaces() {
+    let request =
+        review_file_request("src/my module/lib.rs", Some("origin/main")).expect("quotable path");
+
+    assert!(
+        request
+            .prompt
+            .contains("`git diff origin/main...HEAD -- 'src/my module/lib.rs'`"),
+        "prompt: {}",
+        request.prompt
+    );
+    assert!(
+        request
+            .prompt
+            .contains("`git diff HEAD -- 'src/my module/lib.rs'`"),
+        "prompt: {}",
+        request.prompt
+    );
+    assert_eq!(
+        "changes to 'src/my module/lib.rs' against origin/main".to_string(),
+        request.user_facing_hint
+    );
+}
+
 /// Hitting Enter on an empty custom prompt view does not submit.
 #[test]
 fn custom_prompt_enter_empty_does_not_send() {